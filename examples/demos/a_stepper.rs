@@ -9,6 +9,7 @@ use stereokit_rust::{
     sk::{MainThreadToken, SkInfo},
     system::{Renderer, Text, TextStyle},
     util::named_colors::RED,
+    StereoKitError,
 };
 /// The basic Stepper. This stepper is used for Thread1 demo, we must ensure the StereoKit code stay in the main thread
 /// Default may be called in an other thread
@@ -39,13 +40,13 @@ impl Default for AStepper {
 
 /// All the code here run in the main thread
 impl IStepper for AStepper {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
         self.round_cube = Some(Mesh::generate_rounded_cube(Vec3::ONE / 5.0, 0.2, Some(16)));
         self.text_style = Some(Text::make_style(Font::default(), 0.3, RED));
 
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
@@ -11,6 +11,7 @@ use stereokit_rust::{
     system::{Handed, Input, Lines, Log, Text, TextStyle},
     ui::{Ui, UiCut},
     util::named_colors::{RED, WHITE},
+    StereoKitError,
 };
 
 pub struct Anchor1 {
@@ -24,8 +25,6 @@ pub struct Anchor1 {
     text_style: TextStyle,
 }
 
-unsafe impl Send for Anchor1 {}
-
 impl Default for Anchor1 {
     fn default() -> Self {
         Self {
@@ -42,11 +41,11 @@ impl Default for Anchor1 {
 }
 
 impl IStepper for Anchor1 {
-    fn initialize(&mut self, id: StepperId, sk: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk = Some(sk);
         self.anchors = Anchor::anchors().collect();
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 use stereokit_rust::{
-    event_loop::{IStepper, StepperAction, StepperId},
+    event_loop::{IStepper, StepperId},
     material::{Cull, Material, Transparency},
     maths::{Matrix, Quat, Vec3, Vec4},
     model::{AnimMode, Model},
@@ -11,6 +11,7 @@ use stereokit_rust::{
     tex::SHCubemap,
     tools::notif::HudNotification,
     util::named_colors::{DARK_RED, WHITE},
+    StereoKitError,
 };
 #[derive(Debug)]
 pub struct Anim1 {
@@ -23,8 +24,6 @@ pub struct Anim1 {
     stage: i32,
 }
 
-unsafe impl Send for Anim1 {}
-
 impl Default for Anim1 {
     fn default() -> Self {
         let calcaire = Material::find("clean_tile").unwrap_or_default();
@@ -81,7 +80,7 @@ impl Default for Anim1 {
 }
 
 impl IStepper for Anim1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
         let mut notif = HudNotification::default();
@@ -89,10 +88,8 @@ impl IStepper for Anim1 {
         notif.text = "Close right hand to change animation".into();
 
         let rc_sk = self.sk_info.as_ref().unwrap();
-        let sk = rc_sk.as_ref();
-        let event_loop_proxy = sk.borrow().get_event_loop_proxy().unwrap();
-        let _ = event_loop_proxy.send_event(StepperAction::add("HudNotifAnim1", notif));
-        true
+        rc_sk.borrow().add_stepper("HudNotifAnim1", notif);
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
@@ -14,11 +14,12 @@ use stereokit_rust::{
     sk::{MainThreadToken, SkInfo},
     sound::{Sound, SoundInst},
     sprite::Sprite,
-    system::{Assets, Handed, Input, Log, Renderer, Text, TextStyle},
+    system::{AssetLoad, Assets, Handed, Input, Log, Renderer, Text, TextStyle},
     tex::Tex,
     tools::os_api::{get_assets, PathEntry},
     ui::{Ui, UiBtnLayout},
     util::named_colors::RED,
+    StereoKitError,
 };
 
 const ASSET_DIR: &[&str] = include_asset_tree!("assets");
@@ -42,8 +43,6 @@ pub struct Asset1 {
     text_style: TextStyle,
 }
 
-unsafe impl Send for Asset1 {}
-
 impl Default for Asset1 {
     fn default() -> Self {
         Self {
@@ -77,7 +76,7 @@ impl Default for Asset1 {
 }
 
 impl IStepper for Asset1 {
-    fn initialize(&mut self, id: StepperId, sk: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.asset_files = get_assets(sk.clone(), self.asset_sub_dir.clone(), &self.exts);
         self.sk_info = Some(sk);
@@ -92,7 +91,7 @@ impl IStepper for Asset1 {
 
         Log::diag(format!("{:?}", ASSET_DIR));
 
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
@@ -222,7 +221,16 @@ impl Asset1 {
             } else if Assets::TEXTURE_FORMATS.contains(&ext.as_str()) {
                 let model = Model::new();
                 let mesh = Mesh::generate_plane_up(Vec2::ONE * 6.0, None, true);
-                let tex = Tex::from_file(file_path, true, None).unwrap_or_default();
+                let tex = Tex::from_file(&file_path, true, None).unwrap_or_default();
+                // the texture is shown while loading, a loading error is only reported
+                if let Some(sk_info) = self.sk_info.as_ref() {
+                    let load = AssetLoad::new(Tex::find(tex.get_id()), &file_path);
+                    sk_info.borrow().get_executor().on_loaded(load, |tex| {
+                        if let Err(error) = tex {
+                            Log::warn(format!("Asset1: {}", error))
+                        }
+                    });
+                }
                 let mut material = Material::pbr_clip().copy();
                 material.diffuse_tex(tex).clip_cutoff(0.1);
                 model.get_nodes().add("tex_plane", Matrix::IDENTITY, mesh, material, true);
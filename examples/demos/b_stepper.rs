@@ -9,6 +9,7 @@ use stereokit_rust::{
     sk::{MainThreadToken, SkInfo},
     system::{Log, Renderer, Text},
     util::{named_colors::RED, Time},
+    StereoKitError,
 };
 /// The basic Stepper. This stepper is used for Thread1 demo, we must ensure the StereoKit code stay in the main thread
 /// Default may be called in an other thread
@@ -19,8 +20,6 @@ pub struct BStepper {
     closures: StepperClosures<'static>,
 }
 
-unsafe impl Send for BStepper {}
-
 /// This code may be called in some threads, so no StereoKit code
 impl Default for BStepper {
     fn default() -> Self {
@@ -35,7 +34,7 @@ impl Default for BStepper {
 
 /// All the code here run in the main thread
 impl IStepper for BStepper {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
 
@@ -54,7 +53,7 @@ impl IStepper for BStepper {
             },
             || Log::diag("Closing Stepper B !!!"),
         );
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
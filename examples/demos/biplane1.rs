@@ -1,6 +1,7 @@
 use std::{cell::RefCell, rc::Rc};
 
 use stereokit_rust::{
+    asset_path,
     event_loop::{IStepper, StepperId},
     font::Font,
     material::Material,
@@ -11,6 +12,7 @@ use stereokit_rust::{
     sound::{Sound, SoundInst},
     system::{Log, Renderer, Text, TextStyle},
     util::{named_colors::RED, Time},
+    StereoKitError,
 };
 
 /// The plane1 stepper a flying plane
@@ -32,12 +34,10 @@ pub struct Biplane1 {
     text_style: Option<TextStyle>,
 }
 
-unsafe impl Send for Biplane1 {}
-
 /// This code may be called in main thread
 impl Default for Biplane1 {
     fn default() -> Self {
-        let model = Model::from_file("plane.glb", None).unwrap_or_default();
+        let model = Model::from_file(asset_path!("plane.glb"), None).unwrap_or_default();
         let nodes = model.get_nodes();
         let plane = match nodes.find("Plane") {
             Some(plane) => match plane.get_mesh() {
@@ -47,7 +47,7 @@ impl Default for Biplane1 {
             _ => Mesh::cube(),
         };
         let plane_pose = Pose::new(Vec3::NEG_Z + Vec3::Y * 1.5, None);
-        let mut plane_sound = Sound::from_file("sounds/plane_engine.mp3").unwrap();
+        let mut plane_sound = Sound::from_file(asset_path!("sounds/plane_engine.mp3")).unwrap();
         plane_sound.id("sound_plane");
 
         Self {
@@ -72,14 +72,14 @@ impl Default for Biplane1 {
 
 /// All the code here run in the main thread
 impl IStepper for Biplane1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
         self.text_style = Some(Text::make_style(Font::default(), 0.3, RED));
 
         self.plane_sound_inst = Some(self.plane_sound.play(self.plane_pose.position, Some(1.0)));
 
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
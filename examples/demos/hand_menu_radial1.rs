@@ -3,6 +3,7 @@ use std::{cell::RefCell, rc::Rc};
 use stereokit_rust::{
     event_loop::{IStepper, StepperAction, StepperId},
     framework::{HandMenuAction, HandMenuRadial, HandRadial, HandRadialLayer},
+    include_assets,
     material::{Cull, Material},
     maths::{Matrix, Quat, Vec2, Vec3, Vec4},
     mesh::Mesh,
@@ -10,15 +11,27 @@ use stereokit_rust::{
     sk::{MainThreadToken, SkInfo},
     system::Log,
     tex::{SHCubemap, Tex, TexSample},
-    tools::{log_window::SHOW_LOG_WINDOW, screenshoot::SHOW_SCREENSHOT_WINDOW},
+    tools::{
+        asset_browser::ShowAssetBrowser, log_window::ShowLogWindow, profiler_window::ShowProfilerWindow,
+        screenshoot::ShowScreenshotWindow,
+    },
     util::{
         named_colors::{BLACK, BLUE, BURLY_WOOD, LIGHT_BLUE, LIGHT_CYAN, RED, SEA_GREEN, STEEL_BLUE, WHITE, YELLOW},
         Color128, Gradient, ShLight, SphericalHarmonics,
     },
+    StereoKitError,
 };
 
-pub const SHOW_FLOOR: &str = "ShowFloor";
-pub const CHANGE_FLOOR: &str = "ChangeFlor";
+// The icons of the menu are embedded in the binary
+include_assets!(icons, "icons", ".png");
+
+/// Show or hide the floor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowFloor(pub bool);
+
+/// Change the material of the floor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeFloor(pub u8);
 
 /// The basic Stepper. This stepper is used for Thread1 demo, we must ensure the StereoKit code stay in the main thread
 /// Default may be called in an other thread
@@ -35,8 +48,6 @@ pub struct HandMenuRadial1 {
     pub floor: u8,
 }
 
-unsafe impl Send for HandMenuRadial1 {}
-
 /// This code may be called in some threads, so no StereoKit code
 impl Default for HandMenuRadial1 {
     fn default() -> Self {
@@ -133,7 +144,7 @@ impl Default for HandMenuRadial1 {
 
 /// All the code here run in the main thread
 impl IStepper for HandMenuRadial1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
 
         // Open or close the log window
@@ -141,7 +152,7 @@ impl IStepper for HandMenuRadial1 {
         let mut show_log = true;
         let event_loop_proxy = sk_info.borrow().get_event_loop_proxy().unwrap();
         let send_event_show_log = move |show: bool| {
-            let _ = &event_loop_proxy.send_event(StepperAction::event(id, SHOW_LOG_WINDOW, &show.to_string()));
+            let _ = &event_loop_proxy.send_event(StepperAction::typed_event(id, ShowLogWindow(show)));
         };
 
         // Open or close the screenshot window
@@ -149,14 +160,30 @@ impl IStepper for HandMenuRadial1 {
         let mut show_screenshot = false;
         let event_loop_proxy = sk_info.borrow().get_event_loop_proxy().unwrap();
         let send_event_show_screenshot = move |show: bool| {
-            let _ = &event_loop_proxy.send_event(StepperAction::event(id, SHOW_SCREENSHOT_WINDOW, &show.to_string()));
+            let _ = &event_loop_proxy.send_event(StepperAction::typed_event(id, ShowScreenshotWindow(show)));
+        };
+
+        // Open or close the profiler window
+        let id = self.id.clone();
+        let mut show_profiler = false;
+        let event_loop_proxy = sk_info.borrow().get_event_loop_proxy().unwrap();
+        let send_event_show_profiler = move |show: bool| {
+            let _ = &event_loop_proxy.send_event(StepperAction::typed_event(id, ShowProfilerWindow(show)));
+        };
+
+        // Open or close the asset browser
+        let id = self.id.clone();
+        let mut show_assets = false;
+        let event_loop_proxy = sk_info.borrow().get_event_loop_proxy().unwrap();
+        let send_event_show_assets = move |show: bool| {
+            let _ = &event_loop_proxy.send_event(StepperAction::typed_event(id, ShowAssetBrowser(show)));
         };
 
         // Change the material of the floor
         let id = self.id.clone();
         let event_loop_proxy = sk_info.borrow().get_event_loop_proxy().unwrap();
-        let change_floor = move |floor_index: String| {
-            let _ = &event_loop_proxy.send_event(StepperAction::event(id, CHANGE_FLOOR, &floor_index));
+        let change_floor = move |floor_index: u8| {
+            let _ = &event_loop_proxy.send_event(StepperAction::typed_event(id, ChangeFloor(floor_index)));
         };
         let change_floor0 = change_floor.clone();
         let change_floor1 = change_floor.clone();
@@ -165,15 +192,15 @@ impl IStepper for HandMenuRadial1 {
         let change_floor4 = change_floor.clone();
 
         let mut menu_ico = Material::pbr_clip().copy();
-        let tex = Tex::from_file("icons/hamburger.png", true, None).unwrap_or_default();
+        let tex = Tex::from_memory(icons::HAMBURGER_PNG.data, true, None).unwrap_or_default();
         menu_ico.diffuse_tex(tex).clip_cutoff(0.1);
 
         let mut screenshot_ico = Material::pbr_clip().copy();
-        let tex = Tex::from_file("icons/screenshot.png", true, None).unwrap_or_default();
+        let tex = Tex::from_memory(icons::SCREENSHOT_PNG.data, true, None).unwrap_or_default();
         screenshot_ico.diffuse_tex(tex).clip_cutoff(0.1);
 
         let mut log_ico = Material::pbr_clip().copy();
-        let tex = Tex::from_file("icons/log_viewer.png", true, None).unwrap_or_default();
+        let tex = Tex::from_memory(icons::LOG_VIEWER_PNG.data, true, None).unwrap_or_default();
         log_ico.diffuse_tex(tex).clip_cutoff(0.1);
 
         //---- Sky domes and floor
@@ -270,7 +297,7 @@ impl IStepper for HandMenuRadial1 {
                             "Black tile",
                             None,
                             move || {
-                                change_floor0.clone()("0".into());
+                                change_floor0.clone()(0);
                             },
                             if self.floor == 0 { HandMenuAction::Checked(1) } else { HandMenuAction::Unchecked(1) },
                         ),
@@ -278,7 +305,7 @@ impl IStepper for HandMenuRadial1 {
                             "Parquet",
                             None,
                             move || {
-                                change_floor1.clone()("1".into());
+                                change_floor1.clone()(1);
                             },
                             if self.floor == 1 { HandMenuAction::Checked(1) } else { HandMenuAction::Unchecked(1) },
                         ),
@@ -286,7 +313,7 @@ impl IStepper for HandMenuRadial1 {
                             "sea",
                             None,
                             move || {
-                                change_floor2.clone()("2".into());
+                                change_floor2.clone()(2);
                             },
                             if self.floor == 2 { HandMenuAction::Checked(1) } else { HandMenuAction::Unchecked(1) },
                         ),
@@ -294,7 +321,7 @@ impl IStepper for HandMenuRadial1 {
                             "water",
                             None,
                             move || {
-                                change_floor3.clone()("3".into());
+                                change_floor3.clone()(3);
                             },
                             if self.floor == 3 { HandMenuAction::Checked(1) } else { HandMenuAction::Unchecked(1) },
                         ),
@@ -302,7 +329,7 @@ impl IStepper for HandMenuRadial1 {
                             "None",
                             None,
                             move || {
-                                change_floor4.clone()("4".into());
+                                change_floor4.clone()(4);
                             },
                             if self.floor == 4 { HandMenuAction::Checked(1) } else { HandMenuAction::Unchecked(1) },
                         ),
@@ -328,27 +355,41 @@ impl IStepper for HandMenuRadial1 {
                     },
                     HandMenuAction::Checked(3),
                 ),
+                HandRadial::item(
+                    "Profiler",
+                    None,
+                    move || {
+                        show_profiler = !show_profiler;
+                        send_event_show_profiler.clone()(show_profiler);
+                    },
+                    HandMenuAction::Unchecked(4),
+                ),
+                HandRadial::item(
+                    "Assets",
+                    None,
+                    move || {
+                        show_assets = !show_assets;
+                        send_event_show_assets.clone()(show_assets);
+                    },
+                    HandMenuAction::Unchecked(5),
+                ),
                 HandRadial::item("Close", None, || {}, HandMenuAction::Close),
             ],
         ));
 
-        let event_loop_proxy = sk_info.borrow().get_event_loop_proxy().unwrap();
-        let _err = event_loop_proxy.send_event(StepperAction::add("HandMenuStepper1", hand_menu_stepper));
+        sk_info.borrow().add_stepper("HandMenuStepper1", hand_menu_stepper);
 
         self.sk_info = Some(sk_info);
 
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
-        for e in token.get_event_report().iter() {
-            if let StepperAction::Event(_, key, value) = e {
-                if key.eq(CHANGE_FLOOR) {
-                    self.floor = value.parse().unwrap_or(0);
-                } else if key.eq(SHOW_FLOOR) {
-                    self.show_floor = value.parse().unwrap_or(true);
-                }
-            }
+        for (_, ChangeFloor(floor)) in token.get_events::<ChangeFloor>() {
+            self.floor = *floor;
+        }
+        for (_, ShowFloor(show)) in token.get_events::<ShowFloor>() {
+            self.show_floor = *show;
         }
         self.draw(token)
     }
@@ -14,6 +14,7 @@ use stereokit_rust::{
         named_colors::{BLACK, BLUE, GREEN, RED, WHITE, YELLOW_GREEN},
         Time,
     },
+    StereoKitError,
 };
 
 pub const SPHERE_RADIUS: f32 = 0.4;
@@ -33,8 +34,6 @@ pub struct Math1 {
     text_style: TextStyle,
 }
 
-unsafe impl Send for Math1 {}
-
 impl Default for Math1 {
     fn default() -> Self {
         let transform_ico_sphere = Matrix::ts(Vec3::NEG_Z * 0.5 + Vec3::X + Vec3::Y * 1.5, Vec3::ONE * 0.3);
@@ -61,10 +60,10 @@ impl Default for Math1 {
 }
 
 impl IStepper for Math1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
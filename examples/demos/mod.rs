@@ -1,5 +1,5 @@
 use stereokit_rust::{
-    event_loop::{StepperAction, StepperId},
+    event_loop::{StepperAction, StepperId, StepperParams, StepperRegistry},
     sk::Sk,
 };
 
@@ -28,6 +28,9 @@ use self::{
     text2::Text2, threads1::Threads1, threads2::Threads2, ui1::Ui1,
 };
 
+/// Registers a demo stepper type under a name.
+type RegisterDemo = fn(&StepperRegistry, &'static str);
+
 pub struct Test {
     pub name: String,
     pub launcher: Box<dyn (Fn(&mut Sk) -> StepperId) + 'static>,
@@ -38,73 +41,35 @@ impl Test {
         Self { name: name.as_ref().to_string(), launcher: Box::new(launcher) }
     }
 
-    pub fn get_tests() -> Box<[Test]> {
-        let tests = [
-            Test::new("Test A", |sk| {
-                sk.push_action(StepperAction::add_default::<AStepper>("Test A"));
-                "Test A".to_string()
-            }),
-            Test::new("Test B", |sk| {
-                sk.push_action(StepperAction::add_default::<BStepper>("Test B"));
-                "Test B".to_string()
-            }),
-            Test::new("Threads1", |sk| {
-                sk.push_action(StepperAction::add_default::<Threads1>("Threads1"));
-                "Threads1".to_string()
-            }),
-            Test::new("Threads2", |sk| {
-                sk.push_action(StepperAction::add_default::<Threads2>("Threads2"));
-                "Threads2".to_string()
-            }),
-            Test::new("Anchor1", |sk| {
-                sk.push_action(StepperAction::add_default::<Anchor1>("Anchor1"));
-                "Anchor1".to_string()
-            }),
-            Test::new("Text1", |sk| {
-                sk.push_action(StepperAction::add_default::<Text1>("Text1"));
-                "Text1".to_string()
-            }),
-            Test::new("Text2", |sk| {
-                sk.push_action(StepperAction::add_default::<Text2>("Text2"));
-                "Text2".to_string()
-            }),
-            Test::new("Sprite1", |sk| {
-                sk.push_action(StepperAction::add_default::<Sprite1>("Sprite1"));
-                "Sprite1".to_string()
-            }),
-            Test::new("Tex1", |sk| {
-                sk.push_action(StepperAction::add_default::<Tex1>("Tex1"));
-                "Tex1".to_string()
-            }),
-            Test::new("Ui1", |sk| {
-                sk.push_action(StepperAction::add_default::<Ui1>("Ui1"));
-                "Ui1".to_string()
-            }),
-            Test::new("Anim1", |sk| {
-                sk.push_action(StepperAction::add_default::<Anim1>("Anim1"));
-                "Anim1".to_string()
-            }),
-            Test::new("Shader1", |sk| {
-                sk.push_action(StepperAction::add_default::<Shader1>("Shader1"));
-                "Shader1".to_string()
-            }),
-            Test::new("Math1", |sk| {
-                sk.push_action(StepperAction::add_default::<Math1>("Math1"));
-                "Math1".to_string()
-            }),
-            Test::new("Asset1", |sk| {
-                sk.push_action(StepperAction::add_default::<Asset1>("Asset1"));
-                "Asset1".to_string()
-            }),
-            Test::new("RenderList1", |sk| {
-                sk.push_action(StepperAction::add_default::<RenderList1>("RenderList1"));
-                "RenderList1".to_string()
-            }),
-            Test::new("Biplane1", |sk| {
-                sk.push_action(StepperAction::add_default::<Biplane1>("Biplane1"));
-                "Biplane1".to_string()
-            }),
+    /// Register all the demos in the stepper registry, then list them in the order of the demo window.
+    pub fn get_tests(sk: &Sk) -> Box<[Test]> {
+        // each demo is registered under the name listed in the demo window
+        let demos: [(&'static str, RegisterDemo); 16] = [
+            ("Test A", StepperRegistry::register_default::<AStepper>),
+            ("Test B", StepperRegistry::register_default::<BStepper>),
+            ("Threads1", StepperRegistry::register_default::<Threads1>),
+            ("Threads2", StepperRegistry::register_default::<Threads2>),
+            ("Anchor1", StepperRegistry::register_default::<Anchor1>),
+            ("Text1", StepperRegistry::register_default::<Text1>),
+            ("Text2", StepperRegistry::register_default::<Text2>),
+            ("Sprite1", StepperRegistry::register_default::<Sprite1>),
+            ("Tex1", StepperRegistry::register_default::<Tex1>),
+            ("Ui1", StepperRegistry::register_default::<Ui1>),
+            ("Anim1", StepperRegistry::register_default::<Anim1>),
+            ("Shader1", StepperRegistry::register_default::<Shader1>),
+            ("Math1", StepperRegistry::register_default::<Math1>),
+            ("Asset1", StepperRegistry::register_default::<Asset1>),
+            ("RenderList1", StepperRegistry::register_default::<RenderList1>),
+            ("Biplane1", StepperRegistry::register_default::<Biplane1>),
         ];
+        let registry = sk.get_stepper_registry();
+        let tests = demos.map(|(name, register)| {
+            register(&registry, name);
+            Test::new(name, move |sk| {
+                sk.push_action(StepperAction::add_by_name(name, name, StepperParams::new()));
+                name.to_string()
+            })
+        });
         Box::new(tests)
     }
 }
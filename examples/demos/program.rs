@@ -1,7 +1,7 @@
 use std::{process, sync::Mutex, thread};
 
 use stereokit_rust::{
-    event_loop::{SkClosures, StepperAction, StepperId},
+    event_loop::{Scene, SceneTransition, SkClosures, StepperAction},
     material::Cull,
     maths::{units::*, Pose, Quat, Vec2, Vec3},
     model::Model,
@@ -14,11 +14,13 @@ use stereokit_rust::{
     },
     tex::Tex,
     tools::{
+        asset_browser::AssetBrowser,
         fly_over::FlyOver,
         log_window::{LogItem, LogWindow},
         notif::HudNotification,
         os_api::{get_display_refresh_rate, set_display_refresh_rate},
-        passthrough_fb_ext::{PassthroughFbExt, PASSTHROUGH_FLIP},
+        passthrough_fb_ext::{PassthroughFbExt, PassthroughFlip},
+        profiler_window::ProfilerWindow,
         screenshoot::ScreenshotViewer,
         virtual_kbd_meta::VirtualKbdMETA,
     },
@@ -31,7 +33,7 @@ use winit::event_loop::EventLoop;
 static LOG_LOG: Mutex<Vec<LogItem>> = Mutex::new(vec![]);
 
 use super::{
-    hand_menu_radial1::{HandMenuRadial1, SHOW_FLOOR},
+    hand_menu_radial1::{HandMenuRadial1, ShowFloor},
     Test,
 };
 pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool, start_test: String) {
@@ -42,6 +44,11 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
     Renderer::scaling(1.5);
     Renderer::multisample(4);
 
+    // A demo that panics is removed instead of closing the whole program
+    sk.catch_stepper_panics(true);
+    // Windows and tools find back their settings from the previous session
+    sk.persist_stepper_state(!is_testing);
+
     let mut window_demo_pose = Pose::new(Vec3::new(-0.7, 1.5, -0.3), Some(Quat::look_dir(Vec3::new(1.0, 0.0, 1.0))));
     let window_demo_show = false;
 
@@ -52,7 +59,6 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
     let run_seconds = 0.0f32;
     // let mut run_frames = 2;
     // let mut test_index = 0;
-    let mut active_scene: Option<StepperId> = None;
     let mut next_scene: Option<&Test> = None;
     let mut scene_frame = 0;
     let mut scene_time = 0.0f32;
@@ -101,7 +107,7 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
     } else {
         notif.text = "Press menu button to open the hand menu".into();
     }
-    sk.push_action(StepperAction::add("HudNotif1", notif));
+    sk.add_stepper("HudNotif1", notif);
 
     let mobile = Model::from_file("mobiles.gltf", Some(Shader::pbr())).unwrap();
     Log::diag(format!("{:?}", mobile.get_id()));
@@ -109,17 +115,24 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
         Log::diag(format!("{:?}", iter.get_mesh().unwrap().get_id()));
     }
 
-    sk.push_action(StepperAction::add_default::<HandMenuRadial1>("HandMenuRadial1"));
-    sk.push_action(StepperAction::add("LogWindow", log_window));
-    sk.push_action(StepperAction::add_default::<ScreenshotViewer>("Screenshoot"));
-    sk.push_action(StepperAction::add_default::<FlyOver>("FlyOver"));
+    sk.add_stepper_default::<HandMenuRadial1>("HandMenuRadial1");
+    sk.add_stepper("LogWindow", log_window);
+    sk.add_stepper_default::<ScreenshotViewer>("Screenshoot");
+    sk.add_stepper_default::<ProfilerWindow>("ProfilerWindow");
+    sk.add_stepper_default::<AssetBrowser>("AssetBrowser");
+    sk.add_stepper_default::<FlyOver>("FlyOver");
+    #[cfg(all(debug_assertions, target_os = "linux"))]
+    sk.add_stepper_default::<stereokit_rust::tools::hot_reload::HotReload>("HotReload");
+    // report the assets never released when the scenes are changed and at shutdown
+    #[cfg(debug_assertions)]
+    sk.get_asset_leaks().enable(true);
     let mut passthrough = false;
     let passthrough_enabled = BackendOpenXR::ext_enabled("XR_FB_passthrough");
     if passthrough_enabled {
-        sk.push_action(StepperAction::add_default::<PassthroughFbExt>("PassthroughFbExt"));
+        sk.add_stepper_default::<PassthroughFbExt>("PassthroughFbExt");
         if passthrough {
-            sk.push_action(StepperAction::event("main".into(), PASSTHROUGH_FLIP, "1"));
-            sk.push_action(StepperAction::event("main".into(), SHOW_FLOOR, "false"));
+            sk.push_action(StepperAction::typed_event("main".into(), PassthroughFlip(true)));
+            sk.push_action(StepperAction::typed_event("main".into(), ShowFloor(false)));
             Log::diag("Passthrough Activated at start !!");
         } else {
             Log::diag("Passthrough Deactived at start !!");
@@ -129,7 +142,7 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
     }
     let virtual_kbd_enabled = BackendOpenXR::ext_enabled("XR_META_virtual_keyboard");
     if virtual_kbd_enabled {
-        sk.push_action(StepperAction::add_default::<VirtualKbdMETA>("VirtualKbdMETA"));
+        sk.add_stepper_default::<VirtualKbdMETA>("VirtualKbdMETA");
         Log::diag("XR_META_virtual_keyboard Ready !!")
     } else {
         Log::diag("No XR_META_virtual_keyboard !!")
@@ -143,7 +156,7 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
     // let mut multisample = Renderer::get_multisample() as f32;
     let mut fps = 72.0;
 
-    let tests = Test::get_tests();
+    let tests = Test::get_tests(&sk);
 
     if !start_test.is_empty() {
         for test in tests.iter() {
@@ -178,18 +191,13 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
                 Time::set_time(Time::get_total() + 1.0 / 90.0, 1.0 / 90.0)
             }
 
-            if let Some(next_s) = &next_scene {
-                match &active_scene {
-                    Some(active_stepper) => {
-                        sk.push_action(StepperAction::remove(active_stepper.clone()));
-                        active_scene = None;
-                        // As we can relaunch the same IStepper, we have to be sure the previous is closed so we leave
-                        // this frame here to execute the StepperAction::remove before launching next IStepper.
-                        // So 2 frames without any IStepper.
-                        return;
-                    }
-                    None => {}
+            if let Some(next_s) = next_scene.take() {
+                // The steppers of the previous demo are removed with its scene before the launcher adds the new ones.
+                let mut scene = Scene::new(&next_s.name);
+                if !is_testing {
+                    scene.transition(SceneTransition::fade(0.4));
                 }
+                sk.replace_scene(scene);
                 // if is_testing {
                 //     Time::set_time(0.0, 0.0);
                 //     Input::hand_visible(Handed::Max, false);
@@ -198,9 +206,8 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
                 //     Assets::block_for_priority(i32::MAX);
                 // }
                 let next_launcher = (next_s.launcher)(sk);
-                active_scene = Some(next_launcher);
+                Log::diag(format!("Scene {} launched {}", next_s.name, next_launcher));
                 scene_time = Time::get_totalf();
-                next_scene = None;
             }
             scene_frame += 1;
 
@@ -282,16 +289,13 @@ pub fn launch(mut sk: Sk, event_loop: EventLoop<StepperAction>, is_testing: bool
                 if passthrough_enabled {
                     if let Some(new_value) = Ui::toggle("Passthrough MR", passthrough, None) {
                         passthrough = new_value;
-                        let mut string_value = "0";
                         if new_value {
                             Log::diag("Activate passthrough");
-                            sk.push_action(StepperAction::event("main".into(), SHOW_FLOOR, "false"));
-                            string_value = "1";
                         } else {
                             Log::diag("Deactivate passthrough");
-                            sk.push_action(StepperAction::event("main".into(), SHOW_FLOOR, "true"));
                         }
-                        sk.push_action(StepperAction::event("main".into(), PASSTHROUGH_FLIP, string_value))
+                        sk.push_action(StepperAction::typed_event("main".into(), ShowFloor(!new_value)));
+                        sk.push_action(StepperAction::typed_event("main".into(), PassthroughFlip(new_value)))
                     }
                     Ui::same_line();
                 }
@@ -16,6 +16,7 @@ use stereokit_rust::{
         named_colors::{BLUE_VIOLET, RED},
         Color128, Time,
     },
+    StereoKitError,
 };
 
 pub struct RenderList1 {
@@ -71,16 +72,14 @@ impl Default for RenderList1 {
     }
 }
 
-unsafe impl Send for RenderList1 {}
-
 impl IStepper for RenderList1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
 
         self.old_clear_color = Renderer::get_clear_color();
         Renderer::clear_color(Color128::hsv(0.4, 0.3, 0.5, 1.0));
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
@@ -14,6 +14,7 @@ use stereokit_rust::{
         named_colors::{BLUE, GREEN, LIGHT_BLUE, RED, WHITE},
         Time,
     },
+    StereoKitError,
 };
 
 pub struct Shader1 {
@@ -33,8 +34,6 @@ pub struct Shader1 {
     fps: f64,
 }
 
-unsafe impl Send for Shader1 {}
-
 impl Default for Shader1 {
     fn default() -> Self {
         //------ Materials
@@ -118,10 +117,10 @@ impl Default for Shader1 {
 }
 
 impl IStepper for Shader1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
@@ -16,6 +16,7 @@ use stereokit_rust::{
         named_colors::{BLACK, BLUE, CYAN, LIGHT_BLUE, WHITE, YELLOW},
         Color128, Gradient,
     },
+    StereoKitError,
 };
 
 #[derive(Debug)]
@@ -41,8 +42,6 @@ impl Sprite1 {
     }
 }
 
-unsafe impl Send for Sprite1 {}
-
 impl Default for Sprite1 {
     fn default() -> Self {
         //---- Textures
@@ -97,7 +96,7 @@ impl Default for Sprite1 {
 }
 
 impl IStepper for Sprite1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
 
@@ -122,7 +121,7 @@ impl IStepper for Sprite1 {
         for asset in Assets::all_of_type(AssetType::Sprite) {
             Log::diag(format!("{}", asset));
         }
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
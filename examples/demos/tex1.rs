@@ -1,5 +1,5 @@
 use stereokit_rust::{
-    event_loop::{IStepper, StepperAction, StepperId},
+    event_loop::{IStepper, StepperId},
     material::{Cull, Material},
     maths::Vec4,
     mesh::Mesh,
@@ -12,6 +12,7 @@ use stereokit_rust::{
         named_colors::{BLACK, BLUE, LIGHT_BLUE, RED, YELLOW},
         Color128, Color32, Gradient,
     },
+    StereoKitError,
 };
 
 use glam::{Mat4, Quat, Vec3};
@@ -46,8 +47,6 @@ pub struct Tex1 {
     stage: i8,
 }
 
-unsafe impl Send for Tex1 {}
-
 impl Tex1 {
     /// Change the default title.
     pub fn new(title: String) -> Self {
@@ -311,7 +310,7 @@ impl Default for Tex1 {
 }
 
 impl IStepper for Tex1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
         let mut notif = HudNotification::default();
@@ -319,10 +318,8 @@ impl IStepper for Tex1 {
         notif.text = "Close right hand to change textures".into();
 
         let rc_sk = self.sk_info.as_ref().unwrap();
-        let sk = rc_sk.as_ref();
-        let event_loop_proxy = sk.borrow().get_event_loop_proxy().unwrap();
-        let _ = event_loop_proxy.send_event(StepperAction::add("HudNotifTex1", notif));
-        true
+        rc_sk.borrow().add_stepper("HudNotifTex1", notif);
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
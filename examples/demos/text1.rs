@@ -14,6 +14,7 @@ use stereokit_rust::{
         named_colors::{RED, WHITE},
         Platform,
     },
+    StereoKitError,
 };
 
 pub const FR_KEY_TEXT: &str = r#"²|&|é|"|'|(|\-|è|_|ç|à|)|=|{|}|spr:sk/ui/backspace-\b-8-3|spr:sk/ui/close----close
@@ -55,8 +56,6 @@ pub struct Text1 {
     radio_off: Sprite,
 }
 
-unsafe impl Send for Text1 {}
-
 impl Default for Text1 {
     fn default() -> Self {
         Self {
@@ -83,10 +82,10 @@ impl Default for Text1 {
 }
 
 impl IStepper for Text1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
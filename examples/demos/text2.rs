@@ -9,6 +9,7 @@ use stereokit_rust::{
     system::{Text, TextAlign, TextFit, TextStyle},
     ui::{Ui, UiBtnLayout, UiScroll},
     util::named_colors::{RED, WHITE},
+    StereoKitError,
 };
 
 pub const TEXTY: &str = r#"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789"#;
@@ -36,8 +37,6 @@ pub struct Text2 {
     radio_off: Sprite,
 }
 
-unsafe impl Send for Text2 {}
-
 impl Default for Text2 {
     fn default() -> Self {
         let text_size = 0.02;
@@ -63,10 +62,10 @@ impl Default for Text2 {
 }
 
 impl IStepper for Text2 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
@@ -16,6 +16,7 @@ use stereokit_rust::{
     sk::{MainThreadToken, SkInfo},
     system::{Log, Text, TextStyle},
     util::{named_colors::GREEN_YELLOW, Time},
+    StereoKitError,
 };
 
 use super::a_stepper::AStepper;
@@ -29,8 +30,6 @@ pub struct Threads1 {
     text_style: TextStyle,
 }
 
-unsafe impl Send for Threads1 {}
-
 impl Default for Threads1 {
     fn default() -> Self {
         Self {
@@ -45,7 +44,7 @@ impl Default for Threads1 {
 }
 
 impl IStepper for Threads1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
         let rc_sk = self.sk_info.as_ref().unwrap();
@@ -89,7 +88,7 @@ impl IStepper for Threads1 {
                 Log::err(format!("Thread1, can't send_event final remove_all AStepper: {:?}", error));
             }
         });
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
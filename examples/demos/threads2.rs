@@ -19,6 +19,7 @@ use stereokit_rust::{
     sk::{MainThreadToken, SkInfo},
     system::{Log, Text, TextStyle},
     util::{named_colors::GREEN_YELLOW, Color128},
+    StereoKitError,
 };
 
 pub struct Threads2 {
@@ -34,8 +35,6 @@ pub struct Threads2 {
     text_style: TextStyle,
 }
 
-unsafe impl Send for Threads2 {}
-
 impl Default for Threads2 {
     fn default() -> Self {
         let run_for_ever1 = Arc::new(AtomicBool::new(true));
@@ -57,7 +56,7 @@ impl Default for Threads2 {
 const MODEL_ID: &str = "Threads2/model";
 
 impl IStepper for Threads2 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
         self.model.id(MODEL_ID);
@@ -124,7 +123,7 @@ impl IStepper for Threads2 {
                 }
             }
         }));
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
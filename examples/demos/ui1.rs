@@ -13,6 +13,7 @@ use stereokit_rust::{
         named_colors::{CYAN, DARK_BLUE, RED},
         Color128, Color32, Time,
     },
+    StereoKitError,
 };
 
 const LATHE_BUTTON: [UiLathePt; 6] = [
@@ -49,8 +50,6 @@ pub struct Ui1 {
     pub transform: Matrix,
 }
 
-unsafe impl Send for Ui1 {}
-
 impl Default for Ui1 {
     fn default() -> Self {
         Self {
@@ -67,10 +66,10 @@ impl Default for Ui1 {
 }
 
 impl IStepper for Ui1 {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
-        true
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
@@ -1,15 +1,17 @@
 #[allow(dead_code)]
 #[cfg(not(target_os = "android"))]
 #[cfg(feature = "no-event-loop")]
+#[cfg(not(feature = "event-loop"))]
 fn main() {
     use stereokit_rust::{
         maths::{Pose, Quat, Vec3},
-        sk::{OriginMode, Sk, SkSettings},
-        system::LogLevel,
+        sk::{OriginMode, SkSettings},
+        system::{Log, LogLevel},
+        tools::fly_over::FlyOver,
         ui::Ui,
     };
 
-    let sk = SkSettings::default()
+    let mut sk = SkSettings::default()
         .app_name("stereokit-rust (manual)")
         .assets_folder("assets")
         .origin(OriginMode::Floor)
@@ -17,15 +19,20 @@ fn main() {
         .init()
         .unwrap();
 
+    // Steppers work without winit too, they are stepped by Sk::run (or Sk::step_looped in your own loop)
+    sk.add_stepper_default::<FlyOver>("FlyOver");
+
     let mut window_pose = Pose::new(Vec3::new(0.0, 1.5, -0.5), Some(Quat::from_angles(0.0, 180.0, 0.0)));
-    while let Some(_token) = sk.step() {
-        Ui::window_begin("test window", &mut window_pose, None, None, None);
-        if Ui::button("quit lel", None) {
-            break;
-        }
-        Ui::window_end();
-    }
-    Sk::shutdown();
+    sk.run(
+        |sk| {
+            Ui::window_begin("test window", &mut window_pose, None, None, None);
+            if Ui::button("quit lel", None) {
+                sk.quit(None);
+            }
+            Ui::window_end();
+        },
+        |sk| Log::info(format!("QuitReason is {:?}", sk.get_quit_reason())),
+    );
 }
 
 /// Fake main for android
@@ -5,7 +5,7 @@ use std::{
 
 use crate::{
     maths::{Bool32T, Pose},
-    system::{BtnState, IAsset, Log},
+    system::{Asset, AssetType, BtnState, IAsset, Log},
     StereoKitError,
 };
 
@@ -87,13 +87,26 @@ extern "C" {
 }
 
 impl IAsset for Anchor {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Anchor;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Anchor::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Anchor(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
 }
 
 impl Anchor {
@@ -1,43 +1,74 @@
 use crate::{
-    sk::{sk_step, MainThreadToken, Sk, SkInfo},
-    system::{Input, Log},
+    material::{Cull, DepthTest, Material, Transparency},
+    maths::{Matrix, Pose, Quat, Vec3},
+    mesh::Mesh,
+    sk::{sk_app_focus, AppFocus, MainThreadToken, Sk, SkInfo},
+    system::{AssetLoad, AssetState, IAsset, Input, Log},
+    tex::Tex,
+    tools::{asset_leaks::AssetSnapshot, os_api::get_internal_path},
+    util::{Color128, PickerMode, Platform, Time},
+    StereoKitError,
 };
 use std::{
     any::{Any, TypeId},
-    cell::RefCell,
-    collections::VecDeque,
-    fmt,
+    cell::{Cell, RefCell},
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt, fs,
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    pin::Pin,
+    ptr,
     rc::Rc,
+    str::FromStr,
+    sync::{mpsc, Mutex},
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    time::{Duration, Instant},
 };
+#[cfg(feature = "event-loop")]
 pub use winit;
 
+#[cfg(feature = "event-loop")]
+use crate::{sk::sk_step, system::Key};
+#[cfg(feature = "event-loop")]
 use winit::{
     application::ApplicationHandler,
-    event::WindowEvent,
+    event::{ElementState, Ime, WindowEvent},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
+    keyboard::{KeyCode, PhysicalKey},
     window::WindowId,
 };
 
+#[cfg(feature = "event-loop")]
 type Type<'a> = Box<dyn FnMut(&mut Sk, &MainThreadToken) + 'a>;
 
 /// What winit v0.30 want is : run_app()
 ///
+#[cfg(feature = "event-loop")]
 pub struct SkClosures<'a> {
     sk: Sk,
     token: MainThreadToken,
     on_step: Type<'a>,
     shutdown: Box<dyn FnMut(&mut Sk) + 'a>,
     window_id: Option<WindowId>,
+    pressed_keys: Vec<Key>,
 }
 
+#[cfg(feature = "event-loop")]
 impl ApplicationHandler<StepperAction> for SkClosures<'_> {
     fn user_event(&mut self, _event_loop: &ActiveEventLoop, user_event: StepperAction) {
         Log::diag(format!("UserEvent {:?}", user_event));
-        self.sk.push_action(user_event);
+        match user_event {
+            StepperAction::Run(job) => job(&mut self.sk),
+            _ => self.sk.push_action(user_event),
+        }
     }
 
     fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
         Log::info("Resumed !!");
+        self.sk.steppers.resume();
+        self.sk.push_action(StepperAction::Resumed);
     }
 
     fn window_event(&mut self, event_loop: &ActiveEventLoop, window_id: WindowId, event: WindowEvent) {
@@ -46,10 +77,17 @@ impl ApplicationHandler<StepperAction> for SkClosures<'_> {
             return;
         }
 
-        match event {
+        match &event {
             WindowEvent::RedrawRequested => {}
-            WindowEvent::Focused(_value) => {
+            WindowEvent::Focused(focused) => {
                 self.window_id = Some(window_id);
+                if !focused {
+                    // we won't receive the releases of the keys still pressed
+                    for key in self.pressed_keys.drain(..) {
+                        Input::key_inject_release(key);
+                    }
+                }
+                self.forward_window_event(&event);
             }
             WindowEvent::CloseRequested => {
                 Log::info("SkClosure LoopExiting !!");
@@ -57,16 +95,42 @@ impl ApplicationHandler<StepperAction> for SkClosures<'_> {
                 event_loop.exit();
             }
             WindowEvent::KeyboardInput { device_id: _, event, is_synthetic: _ } => {
-                match &event.state {
-                    winit::event::ElementState::Pressed => {}
-                    winit::event::ElementState::Released => {
-                        Input::text_inject_chars(event.logical_key.to_text().unwrap_or("?"));
+                let key = match event.physical_key {
+                    PhysicalKey::Code(key_code) => key_from_winit(key_code),
+                    PhysicalKey::Unidentified(_) => None,
+                };
+                match event.state {
+                    ElementState::Pressed => {
+                        if let Some(key) = key {
+                            // key repeat is a new press, as StereoKit does with the OS events
+                            Input::key_inject_press(key);
+                            if !self.pressed_keys.contains(&key) {
+                                self.pressed_keys.push(key);
+                            }
+                        }
+                        if let Some(text) = &event.text {
+                            Input::text_inject_chars(text);
+                        }
+                    }
+                    ElementState::Released => {
+                        if let Some(key) = key {
+                            Input::key_inject_release(key);
+                            self.pressed_keys.retain(|pressed| *pressed != key);
+                        }
                     }
                 }
                 // commented due to indiscretion
                 //Log::diag(format!("SkClosure WindowEvent {:?} -> {:?}", window_id, event));
                 return;
             }
+            WindowEvent::Ime(Ime::Commit(text)) => {
+                Input::text_inject_chars(text);
+                return;
+            }
+            WindowEvent::MouseWheel { .. } => {
+                self.forward_window_event(&event);
+                return;
+            }
             _ => (),
         }
 
@@ -91,7 +155,11 @@ impl ApplicationHandler<StepperAction> for SkClosures<'_> {
             action();
         }
 
-        (self.on_step)(&mut self.sk, &self.token);
+        self.sk.get_scheduler().step(&mut self.sk);
+        self.sk.get_executor().step();
+
+        let profiler = self.sk.get_profiler();
+        profiler.measure(Profiler::APP_STEP_ID, || (self.on_step)(&mut self.sk, &self.token));
     }
 
     // commented because it floods the log
@@ -101,6 +169,8 @@ impl ApplicationHandler<StepperAction> for SkClosures<'_> {
 
     fn suspended(&mut self, _event_loop: &ActiveEventLoop) {
         Log::info("SkClosure Suspended !!");
+        self.sk.steppers.suspend();
+        self.sk.push_action(StepperAction::Suspended);
     }
 
     fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
@@ -112,7 +182,15 @@ impl ApplicationHandler<StepperAction> for SkClosures<'_> {
     }
 }
 
+#[cfg(feature = "event-loop")]
 impl<'a> SkClosures<'a> {
+    /// Send the event to the steppers if Sk::forward_window_events is set.
+    fn forward_window_event(&mut self, event: &WindowEvent) {
+        if self.sk.forward_window_events {
+            self.sk.push_action(StepperAction::typed_event("SkClosures".into(), event.clone()));
+        }
+    }
+
     pub fn run_app<U: FnMut(&mut Sk, &MainThreadToken) + 'a, S: FnMut(&mut Sk) + 'a>(
         sk: Sk,
         event_loop: EventLoop<StepperAction>,
@@ -123,17 +201,120 @@ impl<'a> SkClosures<'a> {
             sk,
             on_step: Box::new(step),
             shutdown: Box::new(shutdown),
-            token: MainThreadToken {
-                #[cfg(feature = "event-loop")]
-                event_report: vec![],
-            },
+            token: MainThreadToken { event_report: vec![] },
             window_id: None,
+            pressed_keys: vec![],
         };
         event_loop.set_control_flow(ControlFlow::Poll);
         let _ = event_loop.run_app(&mut this);
     }
 }
 
+/// The StereoKit key for a winit physical key, if there is one. Left and right modifiers are merged.
+#[cfg(feature = "event-loop")]
+pub fn key_from_winit(key_code: KeyCode) -> Option<Key> {
+    let key = match key_code {
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::Enter | KeyCode::NumpadEnter => Key::Return,
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => Key::Shift,
+        KeyCode::ControlLeft | KeyCode::ControlRight => Key::Ctrl,
+        KeyCode::AltLeft | KeyCode::AltRight => Key::Alt,
+        KeyCode::CapsLock => Key::CapsLock,
+        KeyCode::Escape => Key::Esc,
+        KeyCode::Space => Key::Space,
+        KeyCode::End => Key::End,
+        KeyCode::Home => Key::Home,
+        KeyCode::ArrowLeft => Key::Left,
+        KeyCode::ArrowRight => Key::Right,
+        KeyCode::ArrowUp => Key::Up,
+        KeyCode::ArrowDown => Key::Down,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::PrintScreen => Key::PrintScreen,
+        KeyCode::Insert => Key::KeyInsert,
+        KeyCode::Delete => Key::Del,
+        KeyCode::Digit0 => Key::Key0,
+        KeyCode::Digit1 => Key::Key1,
+        KeyCode::Digit2 => Key::Key2,
+        KeyCode::Digit3 => Key::Key3,
+        KeyCode::Digit4 => Key::Key4,
+        KeyCode::Digit5 => Key::Key5,
+        KeyCode::Digit6 => Key::Key6,
+        KeyCode::Digit7 => Key::Key7,
+        KeyCode::Digit8 => Key::Key8,
+        KeyCode::Digit9 => Key::Key9,
+        KeyCode::KeyA => Key::A,
+        KeyCode::KeyB => Key::B,
+        KeyCode::KeyC => Key::C,
+        KeyCode::KeyD => Key::D,
+        KeyCode::KeyE => Key::E,
+        KeyCode::KeyF => Key::F,
+        KeyCode::KeyG => Key::G,
+        KeyCode::KeyH => Key::H,
+        KeyCode::KeyI => Key::I,
+        KeyCode::KeyJ => Key::J,
+        KeyCode::KeyK => Key::K,
+        KeyCode::KeyL => Key::L,
+        KeyCode::KeyM => Key::M,
+        KeyCode::KeyN => Key::N,
+        KeyCode::KeyO => Key::O,
+        KeyCode::KeyP => Key::P,
+        KeyCode::KeyQ => Key::Q,
+        KeyCode::KeyR => Key::R,
+        KeyCode::KeyS => Key::S,
+        KeyCode::KeyT => Key::T,
+        KeyCode::KeyU => Key::U,
+        KeyCode::KeyV => Key::V,
+        KeyCode::KeyW => Key::W,
+        KeyCode::KeyX => Key::X,
+        KeyCode::KeyY => Key::Y,
+        KeyCode::KeyZ => Key::Z,
+        KeyCode::Numpad0 => Key::Numpad0,
+        KeyCode::Numpad1 => Key::Numpad1,
+        KeyCode::Numpad2 => Key::Numpad2,
+        KeyCode::Numpad3 => Key::Numpad3,
+        KeyCode::Numpad4 => Key::Numpad4,
+        KeyCode::Numpad5 => Key::Numpad5,
+        KeyCode::Numpad6 => Key::Numpad6,
+        KeyCode::Numpad7 => Key::Numpad7,
+        KeyCode::Numpad8 => Key::Numpad8,
+        KeyCode::Numpad9 => Key::Numpad9,
+        KeyCode::F1 => Key::F1,
+        KeyCode::F2 => Key::F2,
+        KeyCode::F3 => Key::F3,
+        KeyCode::F4 => Key::F4,
+        KeyCode::F5 => Key::F5,
+        KeyCode::F6 => Key::F6,
+        KeyCode::F7 => Key::F7,
+        KeyCode::F8 => Key::F8,
+        KeyCode::F9 => Key::F9,
+        KeyCode::F10 => Key::F10,
+        KeyCode::F11 => Key::F11,
+        KeyCode::F12 => Key::F12,
+        KeyCode::Comma => Key::Comma,
+        KeyCode::Period => Key::Period,
+        KeyCode::Slash => Key::SlashFwd,
+        KeyCode::Backslash => Key::SlashBack,
+        KeyCode::Semicolon => Key::Semicolon,
+        KeyCode::Quote => Key::Apostrophe,
+        KeyCode::BracketLeft => Key::BracketOpen,
+        KeyCode::BracketRight => Key::BracketClose,
+        KeyCode::Minus => Key::Minus,
+        KeyCode::Equal => Key::Equals,
+        KeyCode::Backquote => Key::Backtick,
+        KeyCode::SuperLeft => Key::LCmd,
+        KeyCode::SuperRight => Key::RCmd,
+        KeyCode::NumpadMultiply => Key::Multiply,
+        KeyCode::NumpadAdd => Key::Add,
+        KeyCode::NumpadSubtract => Key::Subtract,
+        KeyCode::NumpadDecimal => Key::Decimal,
+        KeyCode::NumpadDivide => Key::Divide,
+        _ => return None,
+    };
+    Some(key)
+}
+
 /// This is a lightweight standard interface for fire-and-forget systems that can be attached to StereoKit! This is
 /// particularly handy for extensions/plugins that need to run in the background of your application, or even for
 /// managing some of your own simpler systems.
@@ -146,11 +327,12 @@ impl<'a> SkClosures<'a> {
 /// ISteppers also pay attention to threading! Initialize and Step always happen on the main thread, even if the
 /// constructor is called on a different one.
 /// <https://stereokit.net/Pages/StereoKit.Framework/IStepper.html>
-pub trait IStepper {
+pub trait IStepper: Any {
     /// This is called by StereoKit at the start of the next frame, and on the main thread. This happens before
     /// StereoKit’s main Step callback, and always after Sk.initialize.
+    /// If an error is returned, the IStepper is not added and the error is logged.
     /// <https://stereokit.net/Pages/StereoKit.Framework/IStepper/Initialize.html>
-    fn initialize(&mut self, id: StepperId, sk: Rc<RefCell<SkInfo>>) -> bool;
+    fn initialize(&mut self, id: StepperId, sk: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError>;
 
     /// Is this IStepper enabled? When false, StereoKit will not call Step. This can be a good way to temporarily
     /// disable the IStepper without removing or shutting it down.
@@ -168,25 +350,487 @@ pub trait IStepper {
     /// thread, and happens at the start of the next frame, before the main application’s Step callback.
     /// <https://stereokit.net/Pages/StereoKit.Framework/IStepper/Shutdown.html>
     fn shutdown(&mut self) {}
+
+    /// Non canonical function called on the main thread when the application is suspended (winit suspended event on
+    /// Android for instance). This is the place to release the resources you can't keep in background (audio,
+    /// microphone, passthrough...). Called even if the IStepper is not enabled.
+    fn on_suspend(&mut self) {}
+
+    /// Non canonical function called on the main thread when the application is resumed after having been suspended.
+    /// This is the place to reacquire what was released in on_suspend. Called even if the IStepper is not enabled.
+    fn on_resume(&mut self) {}
+
+    /// Non canonical function called on the main thread at the start of the frame where Sk::get_app_focus has changed.
+    /// Called even if the IStepper is not enabled.
+    fn on_focus_change(&mut self, _app_focus: AppFocus) {}
+
+    /// Non canonical function. When should this IStepper be stepped compared to the others? This is read once when the
+    /// IStepper is added, and can be overridden later with [`StepperAction::order`].
+    fn order(&self) -> StepperOrder {
+        StepperOrder::default()
+    }
+
+    /// Non canonical function called on the main thread when the IStepper is removed or the application shuts down,
+    /// before shutdown. The returned values are given back to load_state the next time a stepper with the same
+    /// StepperId is initialized, even after a restart of the application if [`Steppers::persist_state`] is set.
+    /// Return None (the default) if there is nothing to keep.
+    fn save_state(&self) -> Option<StepperParams> {
+        None
+    }
+
+    /// Non canonical function called on the main thread right after a successful initialize, if a state has been
+    /// saved by a stepper with the same StepperId. See [`IStepper::save_state`].
+    fn load_state(&mut self, _state: &StepperParams) {}
+}
+
+/// The phases of a frame. Steppers of a phase are stepped before the ones of the next phase.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StepperPhase {
+    /// Steppers reading the inputs or moving the camera (FlyOver, ...)
+    Input,
+    /// Default phase, the main logic of the app
+    #[default]
+    Simulation,
+    /// Steppers relying on the result of the simulation
+    LateUpdate,
+    /// Windows and menus, drawn after everything has moved
+    Ui,
+}
+
+/// The actions waiting for the next Steppers::step. The steppers added from the main thread don't have to be Send.
+enum QueuedAction {
+    Action(StepperAction),
+    AddLocal(Box<dyn IStepper>, TypeId, StepperId),
+    AddGlobal(Box<dyn IStepper>, TypeId, StepperId),
+    Scene(SceneOp),
+}
+
+/// The transition played when a scene is pushed, popped or replaced. The view fades out to `color`, the scene stack is
+/// changed, then the view fades in. The actions pushed after the scene change wait for it.
+#[derive(Debug, Clone, Copy)]
+pub enum SceneTransition {
+    None,
+    Fade { seconds: f32, color: Color128 },
+}
+
+impl SceneTransition {
+    /// A fade through black lasting `seconds` of real time.
+    pub fn fade(seconds: f32) -> Self {
+        SceneTransition::Fade { seconds, color: Color128::BLACK }
+    }
+}
+
+/// A named group of steppers living together in the scene stack of [`Steppers`]. Only the scene at the top of the
+/// stack is stepped, the ones below are paused (IStepper::on_suspend) until they are on top again, unless the top
+/// scene is an overlay. The steppers added while a scene is at the top belong to it and are removed with it, use
+/// Sk::add_global_stepper for the steppers that must survive scene changes.
+/// ```ignore
+/// let mut scene = Scene::new("Level1");
+/// scene.add_default::<Level1>("Level1").add("HudLevel1", hud).transition(SceneTransition::fade(0.5));
+/// sk.replace_scene(scene);
+/// ```
+pub struct Scene {
+    name: String,
+    steppers: Vec<(Box<dyn IStepper>, TypeId, StepperId)>,
+    transition: SceneTransition,
+    overlay: bool,
+    /// The snapshot given to track_assets.
+    assets: Option<AssetSnapshot>,
+}
+
+impl Scene {
+    pub fn new(name: impl AsRef<str>) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            steppers: vec![],
+            transition: SceneTransition::None,
+            overlay: false,
+            assets: None,
+        }
+    }
+
+    /// Check the assets of this scene against this snapshot when it is popped, instead of a snapshot taken when it is
+    /// pushed. Give it [`crate::tools::asset_leaks::AssetLeaks::scene_snapshot`] before the steppers of the scene are
+    /// constructed, so the assets created by their constructors are checked too.
+    pub fn track_assets(&mut self, snapshot: Option<AssetSnapshot>) -> &mut Self {
+        self.assets = snapshot;
+        self
+    }
+
+    /// Add a stepper initialized when the scene enters the stack.
+    pub fn add<T: IStepper>(&mut self, stepper_id: impl AsRef<str>, stepper: T) -> &mut Self {
+        let stepper_type = stepper.type_id();
+        self.steppers.push((Box::new(stepper), stepper_type, stepper_id.as_ref().to_string()));
+        self
+    }
+
+    /// Add a stepper of type T created with its default value.
+    pub fn add_default<T: IStepper + Default>(&mut self, stepper_id: impl AsRef<str>) -> &mut Self {
+        self.add(stepper_id, T::default())
+    }
+
+    /// The transition played when this scene enters or leaves the stack. Default is SceneTransition::None.
+    pub fn transition(&mut self, transition: SceneTransition) -> &mut Self {
+        self.transition = transition;
+        self
+    }
+
+    /// An overlay scene (menu, pause screen...) lets the scene below keep stepping. Default is false.
+    pub fn overlay(&mut self, overlay: bool) -> &mut Self {
+        self.overlay = overlay;
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+enum SceneOp {
+    Push(Scene),
+    Pop,
+    Replace(Scene),
+    Pause(String, bool),
+}
+
+struct SceneState {
+    serial: u64,
+    name: String,
+    transition: SceneTransition,
+    overlay: bool,
+    paused: bool,
+}
+
+/// A running fade, the scene change is applied when the view is fully faded out.
+struct SceneFade {
+    op: Option<SceneOp>,
+    seconds: f32,
+    color: Color128,
+    elapsed: f32,
+}
+
+/// Where a stepper takes place in the frame. Steppers are sorted by phase, then by priority (lower first), then by
+/// insertion order. The `after` constraints are applied on top of that and win over the phase and the priority.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StepperOrder {
+    pub phase: StepperPhase,
+    pub priority: i32,
+    /// Ids of the steppers that must be stepped before this one. Unknown ids are ignored.
+    pub after: Vec<StepperId>,
+}
+
+impl StepperOrder {
+    /// A new order in the given phase with priority 0 and no constraint.
+    pub fn new(phase: StepperPhase) -> Self {
+        Self { phase, ..Default::default() }
+    }
+
+    /// Set the priority inside the phase. Lower is stepped first.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Add a constraint: this stepper must be stepped after the stepper identified by stepper_id.
+    pub fn after(mut self, stepper_id: impl AsRef<str>) -> Self {
+        self.after.push(stepper_id.as_ref().to_owned());
+        self
+    }
+}
+
+/// The parameters given to the factories of the [`StepperRegistry`]. Values are kept as strings and parsed on demand.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StepperParams {
+    values: HashMap<String, String>,
+}
+
+impl StepperParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, key: impl AsRef<str>, value: impl ToString) -> &mut Self {
+        self.values.insert(key.as_ref().to_string(), value.to_string());
+        self
+    }
+
+    pub fn get_str(&self, key: impl AsRef<str>) -> Option<&str> {
+        self.values.get(key.as_ref()).map(|value| value.as_str())
+    }
+
+    /// The value parsed as T, None if the key is missing or the value can't be parsed.
+    pub fn get<T: FromStr>(&self, key: impl AsRef<str>) -> Option<T> {
+        self.get_str(key).and_then(|value| value.parse().ok())
+    }
+
+    /// The value parsed as T, the default value if the key is missing or the value can't be parsed.
+    pub fn get_or<T: FromStr>(&self, key: impl AsRef<str>, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.values.iter()
+    }
+
+    /// Store a list of floats separated by spaces.
+    pub fn set_floats(&mut self, key: impl AsRef<str>, values: &[f32]) -> &mut Self {
+        let value: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        self.set(key, value.join(" "))
+    }
+
+    /// The list of floats stored with set_floats, None if the key is missing or a value can't be parsed.
+    pub fn get_floats(&self, key: impl AsRef<str>) -> Option<Vec<f32>> {
+        self.get_str(key)?.split_whitespace().map(|value| value.parse().ok()).collect()
+    }
+
+    /// Store a pose as `x y z qx qy qz qw`.
+    pub fn set_pose(&mut self, key: impl AsRef<str>, pose: Pose) -> &mut Self {
+        let (p, q) = (pose.position, pose.orientation);
+        self.set_floats(key, &[p.x, p.y, p.z, q.x, q.y, q.z, q.w])
+    }
+
+    /// The pose stored with set_pose, None if the key is missing or the value is not a pose.
+    pub fn get_pose(&self, key: impl AsRef<str>) -> Option<Pose> {
+        match self.get_floats(key)?.as_slice() {
+            [x, y, z, qx, qy, qz, qw] => Some(Pose::new(Vec3::new(*x, *y, *z), Some(Quat::new(*qx, *qy, *qz, *qw)))),
+            _ => None,
+        }
+    }
+}
+
+/// Read the states saved by [`Steppers::persist_state`], one TOML table per stepper.
+fn read_stepper_states(path: &Path) -> Result<HashMap<StepperId, StepperParams>, StereoKitError> {
+    let invalid = |reason: String| StereoKitError::StepperState(path.to_path_buf(), reason);
+    let text = fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+    let table: toml::Table = text.parse().map_err(|e: toml::de::Error| invalid(e.message().to_string()))?;
+    let mut states = HashMap::new();
+    for (stepper_id, values) in table {
+        let Some(values) = values.as_table() else {
+            return Err(invalid(format!("{} is not a table", stepper_id)));
+        };
+        let mut state = StepperParams::new();
+        for (key, value) in values {
+            match value.as_str() {
+                Some(value) => state.set(key, value),
+                None => return Err(invalid(format!("value {} of {} is not a string", key, stepper_id))),
+            };
+        }
+        states.insert(stepper_id, state);
+    }
+    Ok(states)
+}
+
+/// Write the states, see [`read_stepper_states`].
+fn write_stepper_states(path: &Path, states: &HashMap<StepperId, StepperParams>) -> Result<(), StereoKitError> {
+    let mut stepper_ids: Vec<&StepperId> = states.keys().collect();
+    stepper_ids.sort();
+    let mut text = String::new();
+    for stepper_id in stepper_ids {
+        text.push_str(&format!("[{}]\n", toml_string(stepper_id)));
+        let mut values: Vec<(&String, &String)> = states[stepper_id].iter().collect();
+        values.sort();
+        for (key, value) in values {
+            text.push_str(&format!("{} = {}\n", toml_string(key), toml_string(value)));
+        }
+        text.push('\n');
+    }
+    let write = || {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, text)
+    };
+    write().map_err(|e| StereoKitError::StepperState(path.to_path_buf(), e.to_string()))
+}
+
+/// A TOML basic string with its quotes.
+fn toml_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+type StepperFactory = Rc<dyn Fn(&StepperParams) -> Result<(Box<dyn IStepper>, TypeId), StereoKitError>>;
+
+/// Stepper types registered under a name with a constructor taking [`StepperParams`], so steppers and scenes can be
+/// described by data. Factories run on the main thread, the steppers don't have to be Send.
+/// Get it with Sk::get_stepper_registry or SkInfo::get_stepper_registry.
+/// ```ignore
+/// let registry = sk.get_stepper_registry();
+/// registry.register_default::<FlyOver>("FlyOver");
+/// registry.register("HudNotification", |params| {
+///     let mut notif = HudNotification::default();
+///     notif.text = params.get_or("text", "Hello".to_string());
+///     Ok(notif)
+/// });
+/// sk.push_action(StepperAction::add_by_name("FlyOver", "fly", StepperParams::new()));
+/// sk.replace_scene(registry.load_scene("assets/scenes/demo.toml")?);
+/// ```
+/// A scene file lists the steppers to create with their params:
+/// ```toml
+/// name = "Demo"
+/// # optional fade transition in seconds
+/// fade = 0.5
+/// overlay = false
+///
+/// [[stepper]]
+/// type = "HudNotification"
+/// id = "Hello"
+/// [stepper.params]
+/// text = "Hello from a scene file"
+/// ```
+#[derive(Default, Clone)]
+pub struct StepperRegistry {
+    factories: Rc<RefCell<HashMap<String, StepperFactory>>>,
+}
+
+impl fmt::Debug for StepperRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "StepperRegistry with {} factories", self.factories.borrow().len())
+    }
+}
+
+impl StepperRegistry {
+    /// Register a factory under this name, replacing the previous one if any.
+    pub fn register<T, F>(&self, name: impl AsRef<str>, factory: F)
+    where
+        T: IStepper,
+        F: Fn(&StepperParams) -> Result<T, StereoKitError> + 'static,
+    {
+        let factory: StepperFactory = Rc::new(move |params| {
+            let stepper = factory(params)?;
+            let stepper_type = stepper.type_id();
+            Ok((Box::new(stepper) as Box<dyn IStepper>, stepper_type))
+        });
+        self.factories.borrow_mut().insert(name.as_ref().to_string(), factory);
+    }
+
+    /// Register a stepper type created with its default value, ignoring the params.
+    pub fn register_default<T: IStepper + Default>(&self, name: impl AsRef<str>) {
+        self.register(name, |_params| Ok(T::default()));
+    }
+
+    /// The registered names, sorted.
+    pub fn get_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.factories.borrow().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn contains(&self, name: impl AsRef<str>) -> bool {
+        self.factories.borrow().contains_key(name.as_ref())
+    }
+
+    /// Create a stepper with the factory registered under this name.
+    pub fn create(
+        &self,
+        name: impl AsRef<str>,
+        params: &StepperParams,
+    ) -> Result<(Box<dyn IStepper>, TypeId), StereoKitError> {
+        let name = name.as_ref();
+        // the factory may use the registry
+        let factory = self.factories.borrow().get(name).cloned();
+        match factory {
+            Some(factory) => factory(params),
+            None => Err(StereoKitError::StepperFactory(name.to_string(), "no factory registered".into())),
+        }
+    }
+
+    /// Create a scene from the TOML description of a file. See [`StepperRegistry`] for the format.
+    pub fn load_scene(&self, path: impl AsRef<Path>) -> Result<Scene, StereoKitError> {
+        let path = path.as_ref();
+        let text =
+            fs::read_to_string(path).map_err(|e| StereoKitError::SceneFile(path.to_path_buf(), e.to_string()))?;
+        self.parse_scene(&text, path)
+    }
+
+    /// Create a scene from a TOML description. See [`StepperRegistry`] for the format.
+    pub fn scene_from_str(&self, text: &str) -> Result<Scene, StereoKitError> {
+        self.parse_scene(text, Path::new(""))
+    }
+
+    /// The errors of the description are SceneFile errors of `path`, the ones of the factories are returned as is.
+    fn parse_scene(&self, text: &str, path: &Path) -> Result<Scene, StereoKitError> {
+        let invalid = |reason: String| StereoKitError::SceneFile(path.to_path_buf(), reason);
+        let table: toml::Table = text.parse().map_err(|e: toml::de::Error| invalid(e.message().to_string()))?;
+        let name = table.get("name").and_then(|v| v.as_str()).unwrap_or("scene");
+        let mut scene = Scene::new(name);
+        if let Some(seconds) = table.get("fade").and_then(|v| v.as_float().or(v.as_integer().map(|i| i as f64))) {
+            scene.transition(SceneTransition::fade(seconds as f32));
+        }
+        if let Some(overlay) = table.get("overlay").and_then(|v| v.as_bool()) {
+            scene.overlay(overlay);
+        }
+        let steppers = match table.get("stepper") {
+            Some(toml::Value::Array(steppers)) => steppers.as_slice(),
+            Some(_) => return Err(invalid("stepper must be an array of tables [[stepper]]".into())),
+            None => &[],
+        };
+        for stepper in steppers {
+            let Some(type_name) = stepper.get("type").and_then(|v| v.as_str()) else {
+                return Err(invalid("a [[stepper]] has no type".into()));
+            };
+            let id = stepper.get("id").and_then(|v| v.as_str()).unwrap_or(type_name);
+            let mut params = StepperParams::new();
+            if let Some(toml::Value::Table(values)) = stepper.get("params") {
+                for (key, value) in values {
+                    let value = match value {
+                        toml::Value::String(value) => value.clone(),
+                        toml::Value::Integer(value) => value.to_string(),
+                        toml::Value::Float(value) => value.to_string(),
+                        toml::Value::Boolean(value) => value.to_string(),
+                        _ => {
+                            return Err(invalid(format!("param {} of {} is not a string, a number or a bool", key, id)))
+                        }
+                    };
+                    params.set(key, value);
+                }
+            }
+            let (stepper, type_id) = self.create(type_name, &params)?;
+            scene.steppers.push((stepper, type_id, id.to_string()));
+        }
+        Ok(scene)
+    }
 }
 
 /// List of action on steppers. This is the user events
 pub enum StepperAction {
     /// Add a new stepper of TypeID,  identified by its StepperID
     Add(Box<dyn for<'a> IStepper + Send + 'static>, TypeId, StepperId),
+    /// Add a new stepper, identified by its StepperID, created by the [`StepperRegistry`] factory registered under
+    /// this name
+    AddByName(String, StepperId, StepperParams),
     /// Remove all steppers of TypeID
     RemoveAll(TypeId),
     /// Remove the stepper identified by its StepperID
     Remove(StepperId),
     /// Quit the app,
     Quit(StepperId, String),
-    /// Event sent by a stepper for those who need it.
-    /// Key -> Value  are strings.
-    Event(StepperId, String, String),
+    /// Event sent by a stepper for those who need it. The payload can be of any `Send + 'static` type and steppers
+    /// get the ones they are interested in with [`MainThreadToken::get_events`].
+    /// Key -> Value string events are sent as [`StringEvent`] payloads.
+    Event(StepperId, Box<dyn Any + Send>),
+    /// Change the order of the stepper identified by its StepperID
+    Order(StepperId, StepperOrder),
     /// Suspended
     Suspended,
     /// Resume
     Resumed,
+    /// A closure to run on the main thread, sent by another thread. Run as soon as the event loop receives it, or by
+    /// the scheduler at the next frame if pushed with Sk::push_action.
+    Run(Box<dyn FnOnce(&mut Sk) + Send>),
 }
 
 impl fmt::Debug for StepperAction {
@@ -195,16 +839,26 @@ impl fmt::Debug for StepperAction {
             StepperAction::Add(_stepper, id, stepper_id) => {
                 write!(f, "StepperAction::Add(..., id:{:?}, type_id:{:?}", id, stepper_id)
             }
+            StepperAction::AddByName(name, stepper_id, params) => {
+                write!(f, "StepperAction::AddByName( name:{} id:{:?} params:{:?}", name, stepper_id, params)
+            }
             StepperAction::RemoveAll(type_id) => write!(f, "StepperAction::RemoveAll( type_id:{:?}", type_id),
             StepperAction::Remove(stepper_id) => write!(f, "StepperAction::Remove( id:{:?}", stepper_id),
             StepperAction::Quit(stepper_id, reason) => {
                 write!(f, "StepperAction::Quit() sent by id:{:?} for reason '{}'", stepper_id, reason)
             }
-            StepperAction::Event(stepper_id, key, value) => {
-                write!(f, "StepperAction::Event( id:{:?} => {}->{}", stepper_id, key, value)
+            StepperAction::Event(stepper_id, payload) => match payload.downcast_ref::<StringEvent>() {
+                Some(StringEvent { key, value }) => {
+                    write!(f, "StepperAction::Event( id:{:?} => {}->{}", stepper_id, key, value)
+                }
+                None => write!(f, "StepperAction::Event( id:{:?} => typed payload", stepper_id),
+            },
+            StepperAction::Order(stepper_id, order) => {
+                write!(f, "StepperAction::Order( id:{:?} => {:?}", stepper_id, order)
             }
             StepperAction::Suspended => write!(f, "StepperAction::Suspended"),
             StepperAction::Resumed => write!(f, "StepperAction::Resumed"),
+            StepperAction::Run(_) => write!(f, "StepperAction::Run(...)"),
         }
     }
 }
@@ -231,6 +885,12 @@ impl StepperAction {
         StepperAction::Add(Box::new(stepper), stepper_type, stepper_id.as_ref().to_string())
     }
 
+    /// Instantiate and register the stepper type registered under `name` in the [`StepperRegistry`], giving it the
+    /// params. Unlike add, the stepper is created on the main thread so it doesn't have to be Send.
+    pub fn add_by_name(name: impl AsRef<str>, stepper_id: impl AsRef<str>, params: StepperParams) -> Self {
+        StepperAction::AddByName(name.as_ref().to_string(), stepper_id.as_ref().to_string(), params)
+    }
+
     /// This removes all IStepper instances that are assignable to the generic type specified. This will call the
     /// IStepper’s Shutdown method on each removed instance before returning.
     /// <https://stereokit.net/Pages/StereoKit/SK/RemoveStepper.html>
@@ -245,8 +905,70 @@ impl StepperAction {
         StepperAction::Remove(stepper_id.as_ref().to_string())
     }
 
+    /// Change when the stepper identified by stepper_id is stepped. See [`StepperOrder`].
+    pub fn order(stepper_id: impl AsRef<str>, order: StepperOrder) -> Self {
+        StepperAction::Order(stepper_id.as_ref().to_string(), order)
+    }
+
+    /// Send a Key -> Value string event to all the steppers. They will find it as a [`StringEvent`] payload.
     pub fn event<S: AsRef<str>>(stepper_id: StepperId, key: S, value: S) -> Self {
-        StepperAction::Event(stepper_id, key.as_ref().to_owned(), value.as_ref().to_owned())
+        StepperAction::Event(
+            stepper_id,
+            Box::new(StringEvent { key: key.as_ref().to_owned(), value: value.as_ref().to_owned() }),
+        )
+    }
+
+    /// Send an event with a payload of any type to all the steppers. The ones interested in this type will get it with
+    /// [`MainThreadToken::get_events`], no string parsing needed.
+    pub fn typed_event<T: Any + Send>(stepper_id: StepperId, payload: T) -> Self {
+        StepperAction::Event(stepper_id, Box::new(payload))
+    }
+
+    /// Run the closure on the main thread. Send it with the EventLoopProxy from any thread.
+    /// ```ignore
+    /// let _ = event_loop_proxy.send_event(StepperAction::run(|sk| sk.quit(None)));
+    /// ```
+    pub fn run<F: FnOnce(&mut Sk) + Send + 'static>(job: F) -> Self {
+        StepperAction::Run(Box::new(job))
+    }
+
+    /// Run the closure on the main thread and send its result back through the returned receiver. The receiver gets
+    /// a RecvError if the action is dropped without being run (the app quit meanwhile).
+    /// ```ignore
+    /// let (action, reply) = StepperAction::run_with_reply(|sk| sk.get_stepper_infos().len());
+    /// if event_loop_proxy.send_event(action).is_ok() {
+    ///     let count = reply.recv().unwrap_or(0);
+    /// }
+    /// ```
+    pub fn run_with_reply<R, F>(job: F) -> (Self, mpsc::Receiver<R>)
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut Sk) -> R + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let action = StepperAction::run(move |sk| {
+            // the worker may have given up waiting
+            let _ = sender.send(job(sk));
+        });
+        (action, receiver)
+    }
+}
+
+/// The payload of the events sent with [`StepperAction::event`]. Key -> Value are strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringEvent {
+    pub key: String,
+    pub value: String,
+}
+
+impl StringEvent {
+    /// Read the value as a boolean. "1"/"true" are true, "0"/"false" are false, anything else is None.
+    pub fn get_bool(&self) -> Option<bool> {
+        match self.value.trim() {
+            "1" | "true" => Some(true),
+            "0" | "false" => Some(false),
+            _ => None,
+        }
     }
 }
 
@@ -255,77 +977,1156 @@ struct StepperHandler {
     id: StepperId,
     type_id: TypeId,
     stepper: Box<dyn IStepper>,
+    order: StepperOrder,
+    rank: usize,
+    /// The serial of the scene owning this stepper, None for the global ones.
+    scene: Option<u64>,
+    /// Paused because its scene is not active.
+    paused: bool,
+}
+
+/// The stepper as its concrete type T, if it is one.
+fn downcast_stepper_mut<T: IStepper>(stepper: &mut Box<dyn IStepper>) -> Option<&mut T> {
+    let stepper: &mut dyn Any = stepper.as_mut();
+    stepper.downcast_mut::<T>()
 }
 
 /// A lazy way to identify IStepper instances
 pub type StepperId = String;
 
+/// The event sent to the steppers when one of them panicked and has been removed. See [`Steppers::catch_panics`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepperPanicked {
+    pub stepper_id: StepperId,
+    pub message: String,
+}
+
+/// Run f, catching its panic if catch_panics is true. The error is the panic message.
+fn run_guarded<R>(catch_panics: bool, f: impl FnOnce() -> R) -> Result<R, String> {
+    if !catch_panics {
+        return Ok(f());
+    }
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic payload".to_string()
+        }
+    })
+}
+
+/// What we know about a registered stepper. See [`Steppers::get_stepper_infos`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepperInfo {
+    pub id: StepperId,
+    pub type_id: TypeId,
+    pub enabled: bool,
+    /// The scene owning this stepper, None for the global ones.
+    pub scene: Option<String>,
+    /// true if the scene of this stepper is paused or below the top of the scene stack.
+    pub paused: bool,
+}
+
 /// Steppers manager. Non canonical way you can create a scene with all the Steppers you need
 /// <https://stereokit.net/Pages/StereoKit.Framework/IStepper.html<
-#[cfg(feature = "event-loop")]
 pub struct Steppers {
     sk: Rc<RefCell<SkInfo>>,
     steppers: Vec<StepperHandler>,
-    stepper_actions: VecDeque<StepperAction>,
+    stepper_actions: VecDeque<QueuedAction>,
+    next_rank: usize,
+    order_changed: bool,
+    suspended: bool,
+    app_focus: Option<AppFocus>,
+    catch_panics: bool,
+    profiler: Profiler,
+    scenes: Vec<SceneState>,
+    next_scene_serial: u64,
+    fade: Option<SceneFade>,
+    fade_assets: Option<(Mesh, Material)>,
+    state_file: Option<PathBuf>,
+    saved_states: Option<HashMap<StepperId, StepperParams>>,
 }
 
-#[cfg(feature = "event-loop")]
 impl Steppers {
     // the only way to create a Steppers manager
     pub fn new(sk: Rc<RefCell<SkInfo>>) -> Self {
-        Self { sk, steppers: vec![], stepper_actions: VecDeque::new() }
+        let profiler = sk.borrow().get_profiler();
+        Self {
+            sk,
+            steppers: vec![],
+            stepper_actions: VecDeque::new(),
+            next_rank: 0,
+            order_changed: false,
+            suspended: false,
+            app_focus: None,
+            catch_panics: false,
+            profiler,
+            scenes: vec![],
+            next_scene_serial: 0,
+            fade: None,
+            fade_assets: None,
+            state_file: None,
+            saved_states: None,
+        }
+    }
+
+    /// The file where the states of the steppers are kept between two sessions when persist_state is set.
+    pub const STATE_FILE: &'static str = "steppers_state.toml";
+
+    /// Keep the states returned by [`IStepper::save_state`] in a file of the internal path of the app
+    /// (see os_api::get_internal_path) so they are given back to [`IStepper::load_state`] at the next launch.
+    /// The file is read now and written at shutdown. Default is false, the states are then only kept in memory
+    /// while the app is running.
+    pub fn persist_state(&mut self, value: bool) {
+        if !value {
+            self.state_file = None;
+            return;
+        }
+        let Some(dir) = get_internal_path(self.sk.clone()) else {
+            Log::warn("Steppers: no internal path to persist the states of the steppers");
+            return;
+        };
+        let state_file = dir.join(Self::STATE_FILE);
+        if state_file.exists() {
+            match read_stepper_states(&state_file) {
+                Ok(states) => self.saved_states.get_or_insert_with(HashMap::new).extend(states),
+                Err(error) => Log::warn(format!("Steppers: {}", error)),
+            }
+        }
+        self.state_file = Some(state_file);
+    }
+
+    /// Keep the state of a stepper that is going to be shut down.
+    fn keep_state(saved_states: &mut Option<HashMap<StepperId, StepperParams>>, stepper_h: &StepperHandler) {
+        if let Some(state) = stepper_h.stepper.save_state() {
+            saved_states.get_or_insert_with(HashMap::new).insert(stepper_h.id.clone(), state);
+        }
+    }
+
+    /// Keep the state of the stepper then shut it down. Returns the panic message if one of them panicked.
+    fn shutdown_stepper(
+        catch_panics: bool,
+        saved_states: &mut Option<HashMap<StepperId, StepperParams>>,
+        stepper_h: &mut StepperHandler,
+    ) -> Option<String> {
+        let shutdown = || {
+            Self::keep_state(saved_states, stepper_h);
+            stepper_h.stepper.shutdown()
+        };
+        run_guarded(catch_panics, shutdown).err()
     }
 
     /// push an action to consumme befor next frame
     pub fn push_action(&mut self, action: StepperAction) {
-        self.stepper_actions.push_back(action);
+        self.stepper_actions.push_back(QueuedAction::Action(action));
+    }
+
+    /// Register a stepper created on the main thread. Unlike StepperAction::add the stepper doesn't have to be Send.
+    /// It is initialized before the next step, in the order of the pushed actions.
+    pub fn add_local<T: IStepper>(&mut self, stepper_id: impl AsRef<str>, stepper: T) {
+        let stepper_type = stepper.type_id();
+        self.stepper_actions.push_back(QueuedAction::AddLocal(
+            Box::new(stepper),
+            stepper_type,
+            stepper_id.as_ref().to_string(),
+        ));
+    }
+
+    /// Register a stepper of type T created with its default value on the main thread. See [`Steppers::add_local`]
+    pub fn add_local_default<T: IStepper + Default>(&mut self, stepper_id: impl AsRef<str>) {
+        self.add_local(stepper_id, T::default());
+    }
+
+    /// Register a stepper created on the main thread that doesn't belong to any scene so it survives the scene
+    /// changes. See [`Scene`]
+    pub fn add_global<T: IStepper>(&mut self, stepper_id: impl AsRef<str>, stepper: T) {
+        let stepper_type = stepper.type_id();
+        self.stepper_actions.push_back(QueuedAction::AddGlobal(
+            Box::new(stepper),
+            stepper_type,
+            stepper_id.as_ref().to_string(),
+        ));
+    }
+
+    /// Push a scene on top of the stack, pausing the current top scene unless the new one is an overlay.
+    pub fn push_scene(&mut self, scene: Scene) {
+        self.stepper_actions.push_back(QueuedAction::Scene(SceneOp::Push(scene)));
+    }
+
+    /// Remove the top scene and its steppers, the scene below is resumed.
+    pub fn pop_scene(&mut self) {
+        self.stepper_actions.push_back(QueuedAction::Scene(SceneOp::Pop));
+    }
+
+    /// Remove the top scene and its steppers then push this one. Same as push_scene if the stack is empty.
+    pub fn replace_scene(&mut self, scene: Scene) {
+        self.stepper_actions.push_back(QueuedAction::Scene(SceneOp::Replace(scene)));
+    }
+
+    /// Pause or resume the steppers of the scene with this name.
+    pub fn pause_scene(&mut self, name: impl AsRef<str>, paused: bool) {
+        self.stepper_actions
+            .push_back(QueuedAction::Scene(SceneOp::Pause(name.as_ref().to_string(), paused)));
+    }
+
+    /// The names of the scenes of the stack, the top one last.
+    pub fn get_scenes(&self) -> Vec<String> {
+        self.scenes.iter().map(|scene| scene.name.clone()).collect()
+    }
+
+    /// Add the stepper to the top scene.
+    fn add(&mut self, stepper: Box<dyn IStepper>, type_id: TypeId, stepper_id: StepperId) {
+        let scene = self.scenes.last().map(|scene| scene.serial);
+        self.add_to_scene(stepper, type_id, stepper_id, scene)
+    }
+
+    fn add_to_scene(
+        &mut self,
+        mut stepper: Box<dyn IStepper>,
+        type_id: TypeId,
+        stepper_id: StepperId,
+        scene: Option<u64>,
+    ) {
+        let (id, sk) = (stepper_id.clone(), self.sk.clone());
+        let state = self.saved_states.as_ref().and_then(|states| states.get(&stepper_id));
+        let init = || {
+            stepper.initialize(id, sk)?;
+            if let Some(state) = state {
+                stepper.load_state(state);
+            }
+            Ok::<(), StereoKitError>(())
+        };
+        match run_guarded(self.catch_panics, init) {
+            Ok(Ok(())) => {
+                // balance the on_resume this stepper will get when the app resumes
+                if self.suspended {
+                    stepper.on_suspend();
+                }
+                let order = stepper.order();
+                let rank = self.next_rank;
+                let stepper_h = StepperHandler { id: stepper_id, type_id, stepper, order, rank, scene, paused: false };
+                self.next_rank += 1;
+                self.steppers.push(stepper_h);
+                self.order_changed = true;
+            }
+            // the message of StepperInit already names the stepper
+            Ok(Err(StereoKitError::StepperInit(_, reason))) => {
+                Log::warn(format!("Stepper {} did not initialize: {}", stepper_id, reason))
+            }
+            Ok(Err(error)) => Log::warn(format!("Stepper {} did not initialize: {}", stepper_id, error)),
+            Err(message) => self.report_panic(stepper_id, message),
+        }
+    }
+
+    fn apply_scene_op(&mut self, op: SceneOp) {
+        match op {
+            SceneOp::Push(scene) => {
+                let serial = self.next_scene_serial;
+                self.next_scene_serial += 1;
+                self.scenes.push(SceneState {
+                    serial,
+                    name: scene.name,
+                    transition: scene.transition,
+                    overlay: scene.overlay,
+                    paused: false,
+                });
+                let asset_leaks = self.sk.borrow().get_asset_leaks();
+                asset_leaks.scene_pushed(serial, scene.assets);
+                for (stepper, type_id, stepper_id) in scene.steppers {
+                    self.add_to_scene(stepper, type_id, stepper_id, Some(serial));
+                }
+            }
+            SceneOp::Pop => self.remove_top_scene(None),
+            SceneOp::Replace(scene) => {
+                if !self.scenes.is_empty() {
+                    // the assets of the steppers of the incoming scene are already created
+                    self.remove_top_scene(scene.assets.as_ref());
+                }
+                self.apply_scene_op(SceneOp::Push(scene));
+            }
+            SceneOp::Pause(name, paused) => match self.scenes.iter_mut().find(|scene| scene.name == name) {
+                Some(scene) => scene.paused = paused,
+                None => Log::warn(format!("Scene {} not found, it can't be paused", name)),
+            },
+        }
+    }
+
+    /// Pop the top scene, shutting down its steppers. `incoming` is the snapshot of the scene replacing it, if any.
+    fn remove_top_scene(&mut self, incoming: Option<&AssetSnapshot>) {
+        let Some(scene) = self.scenes.pop() else {
+            Log::warn("No scene to pop");
+            return;
+        };
+        let catch_panics = self.catch_panics;
+        let mut panicked = vec![];
+        for stepper_h in self.steppers.iter_mut().filter(|stepper_h| stepper_h.scene == Some(scene.serial)) {
+            if let Some(message) = Self::shutdown_stepper(catch_panics, &mut self.saved_states, stepper_h) {
+                panicked.push((stepper_h.id.clone(), message));
+            }
+            self.profiler.remove(&stepper_h.id);
+        }
+        self.steppers.retain(|stepper_h| stepper_h.scene != Some(scene.serial));
+        for (stepper_id, message) in panicked {
+            self.report_panic(stepper_id, message);
+        }
+        let (asset_leaks, asset_cache) = {
+            let sk = self.sk.borrow();
+            (sk.get_asset_leaks(), sk.get_asset_cache())
+        };
+        asset_leaks.scene_popped(scene.serial, &scene.name, &asset_cache, incoming);
+    }
+
+    /// The transition to play for this scene change.
+    fn scene_transition(&self, op: &SceneOp) -> SceneTransition {
+        let top = self.scenes.last().map(|scene| scene.transition).unwrap_or(SceneTransition::None);
+        match op {
+            SceneOp::Push(scene) => scene.transition,
+            SceneOp::Pop => top,
+            SceneOp::Replace(scene) if matches!(scene.transition, SceneTransition::None) => top,
+            SceneOp::Replace(scene) => scene.transition,
+            SceneOp::Pause(_, _) => SceneTransition::None,
+        }
+    }
+
+    /// Pause the steppers whose scene is not active anymore, resume the others.
+    fn update_paused(&mut self) {
+        let mut active = vec![];
+        for scene in self.scenes.iter().rev() {
+            if !scene.paused {
+                active.push(scene.serial);
+            }
+            if !scene.overlay {
+                break;
+            }
+        }
+        let suspended = self.suspended;
+        for stepper_h in &mut self.steppers {
+            let paused = stepper_h.scene.is_some_and(|serial| !active.contains(&serial));
+            if paused != stepper_h.paused {
+                stepper_h.paused = paused;
+                // the hooks have already been called if the whole app is suspended
+                if !suspended {
+                    if paused {
+                        stepper_h.stepper.on_suspend()
+                    } else {
+                        stepper_h.stepper.on_resume()
+                    }
+                }
+            }
+        }
+    }
+
+    /// Move the running fade forward, apply its scene change at mid course and draw the overlay.
+    fn step_fade(&mut self, token: &MainThreadToken) {
+        let Some(fade) = self.fade.as_mut() else { return };
+        fade.elapsed += Time::get_step_unscaledf();
+        let half = fade.seconds / 2.0;
+        let (elapsed, seconds, color) = (fade.elapsed, fade.seconds, fade.color);
+        let alpha = if elapsed < half {
+            elapsed / half
+        } else {
+            if let Some(op) = fade.op.take() {
+                self.apply_scene_op(op);
+                self.update_paused();
+            }
+            1.0 - (elapsed - half) / half
+        };
+        let color = Color128 { a: alpha.clamp(0.0, 1.0), ..color };
+        if elapsed >= seconds {
+            self.fade = None;
+        }
+
+        let (mesh, material) = self.fade_assets.get_or_insert_with(|| {
+            let mut material = Material::unlit().copy();
+            material
+                .transparency(Transparency::Blend)
+                .face_cull(Cull::Front)
+                .depth_test(DepthTest::Always)
+                .depth_write(false)
+                .queue_offset(1000);
+            (Mesh::sphere(), material)
+        });
+        let transform = Matrix::ts(Input::get_head().position, Vec3::ONE * 0.5);
+        mesh.draw(token, &*material, transform, Some(color), None);
     }
 
     /// Deque all the actions, create the frame event report, execute all the stepper if quit hasn't be asked
     /// return false if sk_quit must be triggered.
     pub fn step(&mut self, token: &mut MainThreadToken) -> bool {
-        while let Some(action) = self.stepper_actions.pop_front() {
+        // the actions pushed after a scene change wait for it
+        while self.fade.as_ref().and_then(|fade| fade.op.as_ref()).is_none() {
+            let Some(queued) = self.stepper_actions.pop_front() else { break };
+            let action = match queued {
+                QueuedAction::Action(action) => action,
+                QueuedAction::AddLocal(stepper, type_id, stepper_id) => {
+                    self.add(stepper, type_id, stepper_id);
+                    continue;
+                }
+                QueuedAction::AddGlobal(stepper, type_id, stepper_id) => {
+                    self.add_to_scene(stepper, type_id, stepper_id, None);
+                    continue;
+                }
+                QueuedAction::Scene(op) => {
+                    match self.scene_transition(&op) {
+                        SceneTransition::Fade { seconds, color } if seconds > 0.0 => {
+                            self.fade = Some(SceneFade { op: Some(op), seconds, color, elapsed: 0.0 })
+                        }
+                        _ => self.apply_scene_op(op),
+                    }
+                    continue;
+                }
+            };
             match action {
-                StepperAction::Add(mut stepper, type_id, stepper_id) => {
-                    if stepper.initialize(stepper_id.clone(), self.sk.clone()) {
-                        let stepper_h = StepperHandler { id: stepper_id, type_id, stepper };
-                        self.steppers.push(stepper_h);
-                    } else {
-                        Log::warn(format!("Stepper {} did not initialize", stepper_id))
+                StepperAction::Add(stepper, type_id, stepper_id) => self.add(stepper, type_id, stepper_id),
+                StepperAction::AddByName(name, stepper_id, params) => {
+                    let registry = self.sk.borrow().get_stepper_registry();
+                    match registry.create(&name, &params) {
+                        Ok((stepper, type_id)) => self.add(stepper, type_id, stepper_id),
+                        Err(error) => Log::warn(format!("Stepper {} can't be added: {}", stepper_id, error)),
                     }
                 }
                 StepperAction::RemoveAll(stepper_type) => {
+                    let catch_panics = self.catch_panics;
+                    let mut panicked = vec![];
                     for stepper_h in self.steppers.iter_mut().filter(|stepper_h| stepper_h.type_id == stepper_type) {
-                        stepper_h.stepper.shutdown();
+                        if let Some(message) = Self::shutdown_stepper(catch_panics, &mut self.saved_states, stepper_h) {
+                            panicked.push((stepper_h.id.clone(), message));
+                        }
+                        self.profiler.remove(&stepper_h.id);
                     }
                     self.steppers.retain(|stepper_h| stepper_h.type_id != stepper_type);
+                    for (stepper_id, message) in panicked {
+                        self.report_panic(stepper_id, message);
+                    }
                 }
                 StepperAction::Remove(stepper_id) => {
+                    let catch_panics = self.catch_panics;
+                    let mut panicked = vec![];
                     for stepper_h in self.steppers.iter_mut().filter(|stepper_h| stepper_h.id == stepper_id) {
-                        stepper_h.stepper.shutdown()
+                        if let Some(message) = Self::shutdown_stepper(catch_panics, &mut self.saved_states, stepper_h) {
+                            panicked.push(message);
+                        }
                     }
+                    self.profiler.remove(&stepper_id);
                     self.steppers.retain(|i| i.id != stepper_id);
+                    for message in panicked {
+                        self.report_panic(stepper_id.clone(), message);
+                    }
+                }
+                StepperAction::Order(stepper_id, order) => {
+                    match self.steppers.iter_mut().find(|stepper_h| stepper_h.id == stepper_id) {
+                        Some(stepper_h) => {
+                            stepper_h.order = order;
+                            self.order_changed = true;
+                        }
+                        None => Log::warn(format!("Stepper {} not found, its order can't be changed", stepper_id)),
+                    }
                 }
                 StepperAction::Quit(_, _) => return false,
+                StepperAction::Run(job) => {
+                    // Steppers can't lend the Sk, the scheduler can
+                    let mut job = Some(job);
+                    self.sk.borrow().get_scheduler().run(Schedule::next_frame(), move |sk| {
+                        if let Some(job) = job.take() {
+                            job(sk)
+                        }
+                    });
+                }
+                StepperAction::Suspended => {
+                    self.suspend();
+                    token.event_report.push(action)
+                }
+                StepperAction::Resumed => {
+                    self.resume();
+                    token.event_report.push(action)
+                }
                 _ => token.event_report.push(action),
             }
         }
 
-        for stepper_h in &mut self.steppers {
-            stepper_h.stepper.step(token)
+        let app_focus = unsafe { sk_app_focus() };
+        if self.app_focus != Some(app_focus) {
+            if self.app_focus.is_some() {
+                for stepper_h in &mut self.steppers {
+                    stepper_h.stepper.on_focus_change(app_focus)
+                }
+            }
+            self.app_focus = Some(app_focus);
         }
 
+        self.update_paused();
+
+        if self.order_changed {
+            self.order_changed = false;
+            if let Err(error) = self.sort() {
+                Log::err(format!("{}", error));
+            }
+        }
+
+        let catch_panics = self.catch_panics;
+        let mut panicked = vec![];
+        let profiler = &self.profiler;
+        for (index, stepper_h) in self.steppers.iter_mut().enumerate().filter(|(_, stepper_h)| !stepper_h.paused) {
+            let step = || profiler.measure(&stepper_h.id, || stepper_h.stepper.step(token));
+            if let Err(message) = run_guarded(catch_panics, step) {
+                panicked.push((index, message));
+            }
+        }
+        for (index, message) in panicked.into_iter().rev() {
+            let mut stepper_h = self.steppers.remove(index);
+            let _ = run_guarded(catch_panics, || stepper_h.stepper.shutdown());
+            self.profiler.remove(&stepper_h.id);
+            self.report_panic(stepper_h.id, message);
+        }
+
+        self.step_fade(token);
+
         token.event_report.clear();
 
         true
     }
 
+    /// If true, a panic in IStepper::initialize, IStepper::step or IStepper::shutdown is caught, the faulty stepper is
+    /// removed and a [`StepperPanicked`] event is sent to the other steppers. Default is false. This has no effect if
+    /// the app is built with `panic = "abort"`.
+    pub fn catch_panics(&mut self, catch_panics: bool) -> &mut Self {
+        self.catch_panics = catch_panics;
+        self
+    }
+
+    fn report_panic(&mut self, stepper_id: StepperId, message: String) {
+        Log::err(format!("Stepper {} panicked and has been removed: {}", stepper_id, message));
+        self.push_action(StepperAction::typed_event(stepper_id.clone(), StepperPanicked { stepper_id, message }));
+    }
+
+    /// Call IStepper::on_suspend on all the steppers, if not already suspended.
+    pub fn suspend(&mut self) {
+        if !self.suspended {
+            self.suspended = true;
+            for stepper_h in self.steppers.iter_mut().filter(|stepper_h| !stepper_h.paused) {
+                stepper_h.stepper.on_suspend()
+            }
+        }
+    }
+
+    /// Call IStepper::on_resume on all the steppers, if they have been suspended.
+    pub fn resume(&mut self) {
+        if self.suspended {
+            self.suspended = false;
+            for stepper_h in self.steppers.iter_mut().filter(|stepper_h| !stepper_h.paused) {
+                stepper_h.stepper.on_resume()
+            }
+        }
+    }
+
+    /// Get the stepper identified by stepper_id if it is of type T. Steppers added during this frame are available
+    /// after the next step.
+    pub fn get_stepper<T: IStepper>(&mut self, stepper_id: impl AsRef<str>) -> Option<&mut T> {
+        let stepper_id = stepper_id.as_ref();
+        self.steppers
+            .iter_mut()
+            .find(|stepper_h| stepper_h.id == stepper_id)
+            .and_then(|stepper_h| downcast_stepper_mut::<T>(&mut stepper_h.stepper))
+    }
+
+    /// Get all the steppers of type T with their ids. Steppers added during this frame are available after the next
+    /// step.
+    pub fn get_steppers_of<T: IStepper>(&mut self) -> impl Iterator<Item = (&StepperId, &mut T)> {
+        self.steppers.iter_mut().filter_map(|StepperHandler { id, stepper, .. }| {
+            downcast_stepper_mut::<T>(stepper).map(|stepper| (&*id, stepper))
+        })
+    }
+
+    /// Get the id, type and enabled state of all the registered steppers, in the order they are stepped.
+    pub fn get_stepper_infos(&self) -> Vec<StepperInfo> {
+        self.steppers
+            .iter()
+            .map(|stepper_h| StepperInfo {
+                id: stepper_h.id.clone(),
+                type_id: stepper_h.type_id,
+                enabled: stepper_h.stepper.enabled(),
+                scene: stepper_h
+                    .scene
+                    .and_then(|serial| self.scenes.iter().find(|scene| scene.serial == serial))
+                    .map(|scene| scene.name.clone()),
+                paused: stepper_h.paused,
+            })
+            .collect()
+    }
+
+    /// The ids of the steppers in the order they are stepped.
+    pub fn get_order(&self) -> Vec<StepperId> {
+        self.steppers.iter().map(|stepper_h| stepper_h.id.clone()).collect()
+    }
+
+    /// Sort the steppers by phase, priority and insertion order, then apply the `after` constraints. This is done
+    /// automatically before the next step when a stepper is added or its order changed.
+    /// If the constraints have a cycle, the steppers of the cycle are stepped last and an error naming them is
+    /// returned.
+    pub fn sort(&mut self) -> Result<(), StereoKitError> {
+        let count = self.steppers.len();
+        let mut in_degree = vec![0usize; count];
+        let mut followers: Vec<Vec<usize>> = vec![vec![]; count];
+        for (i, stepper_h) in self.steppers.iter().enumerate() {
+            for after_id in &stepper_h.order.after {
+                for (j, _) in self.steppers.iter().enumerate().filter(|(j, other)| *j != i && other.id == *after_id) {
+                    followers[j].push(i);
+                    in_degree[i] += 1;
+                }
+            }
+        }
+
+        let key = |i: usize| {
+            let stepper_h = &self.steppers[i];
+            Reverse((stepper_h.order.phase, stepper_h.order.priority, stepper_h.rank, i))
+        };
+        let mut ready: BinaryHeap<_> = (0..count).filter(|i| in_degree[*i] == 0).map(key).collect();
+        let mut sorted = Vec::with_capacity(count);
+        while let Some(Reverse((_, _, _, i))) = ready.pop() {
+            sorted.push(i);
+            for &j in &followers[i] {
+                in_degree[j] -= 1;
+                if in_degree[j] == 0 {
+                    ready.push(key(j));
+                }
+            }
+        }
+
+        let mut in_cycle: Vec<usize> = (0..count).filter(|i| in_degree[*i] > 0).collect();
+        in_cycle.sort_by_key(|i| key(*i).0);
+        let cycle_ids: Vec<StepperId> = in_cycle.iter().map(|i| self.steppers[*i].id.clone()).collect();
+        sorted.append(&mut in_cycle);
+
+        let mut steppers: Vec<Option<StepperHandler>> = self.steppers.drain(..).map(Some).collect();
+        self.steppers = sorted.into_iter().filter_map(|i| steppers[i].take()).collect();
+
+        if cycle_ids.is_empty() {
+            Ok(())
+        } else {
+            Err(StereoKitError::StepperCycle(cycle_ids.join(", ")))
+        }
+    }
+
     pub fn shutdown(&mut self) {
         self.stepper_actions.clear();
+        self.profiler.reset();
+        let catch_panics = self.catch_panics;
+        let mut panicked = vec![];
         for stepper_h in self.steppers.iter_mut() {
-            stepper_h.stepper.shutdown()
+            if let Some(message) = Self::shutdown_stepper(catch_panics, &mut self.saved_states, stepper_h) {
+                panicked.push((stepper_h.id.clone(), message));
+            }
         }
         self.steppers.clear();
+        for (stepper_id, message) in panicked {
+            self.report_panic(stepper_id, message);
+        }
+        // no stepper is left to receive the StepperPanicked events
+        self.stepper_actions.clear();
+        self.scenes.clear();
+        self.fade = None;
+        let (asset_leaks, asset_cache) = {
+            let sk = self.sk.borrow();
+            (sk.get_asset_leaks(), sk.get_asset_cache())
+        };
+        asset_leaks.steppers_shut_down(&asset_cache);
+        if let (Some(state_file), Some(states)) = (&self.state_file, &self.saved_states) {
+            if let Err(error) = write_stepper_states(state_file, states) {
+                Log::warn(format!("Steppers: {}", error));
+            }
+        }
+    }
+}
+
+/// When a task of the [`Scheduler`] must run. Seconds are measured with the scaled time (Time::get_total) unless
+/// `unscaled` is set.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Schedule {
+    when: ScheduleWhen,
+    unscaled: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ScheduleWhen {
+    NextFrame,
+    AfterSeconds(f64),
+    EverySeconds(f64),
+    AfterFrames(u64),
+    EveryFrames(u64),
+}
+
+impl Schedule {
+    /// Run once, at the next frame.
+    pub fn next_frame() -> Self {
+        Self { when: ScheduleWhen::NextFrame, unscaled: false }
+    }
+
+    /// Run once, when the given amount of seconds has passed.
+    pub fn after(seconds: f64) -> Self {
+        Self { when: ScheduleWhen::AfterSeconds(seconds), unscaled: false }
+    }
+
+    /// Run every given amount of seconds until cancelled. The first run happens after the first period.
+    pub fn every(seconds: f64) -> Self {
+        Self { when: ScheduleWhen::EverySeconds(seconds), unscaled: false }
+    }
+
+    /// Run once, when the given amount of frames has passed.
+    pub fn after_frames(frames: u64) -> Self {
+        Self { when: ScheduleWhen::AfterFrames(frames.max(1)), unscaled: false }
+    }
+
+    /// Run every given amount of frames until cancelled. The first run happens after the first period.
+    pub fn every_frames(frames: u64) -> Self {
+        Self { when: ScheduleWhen::EveryFrames(frames.max(1)), unscaled: false }
+    }
+
+    /// Measure the seconds with Time::get_total_unscaled instead of Time::get_total, so Time::scale has no effect.
+    pub fn unscaled(mut self, unscaled: bool) -> Self {
+        self.unscaled = unscaled;
+        self
+    }
+
+    fn now(&self) -> f64 {
+        if self.unscaled {
+            Time::get_total_unscaled()
+        } else {
+            Time::get_total()
+        }
+    }
+
+    /// The first deadline, in seconds or in frames depending on `when`.
+    fn first_deadline(&self) -> f64 {
+        match self.when {
+            ScheduleWhen::NextFrame => Time::get_frame() as f64 + 1.0,
+            ScheduleWhen::AfterFrames(frames) | ScheduleWhen::EveryFrames(frames) => {
+                (Time::get_frame() + frames) as f64
+            }
+            ScheduleWhen::AfterSeconds(seconds) | ScheduleWhen::EverySeconds(seconds) => self.now() + seconds,
+        }
+    }
+
+    fn is_due(&self, deadline: f64) -> bool {
+        match self.when {
+            ScheduleWhen::NextFrame | ScheduleWhen::AfterFrames(_) | ScheduleWhen::EveryFrames(_) => {
+                Time::get_frame() as f64 >= deadline
+            }
+            ScheduleWhen::AfterSeconds(_) | ScheduleWhen::EverySeconds(_) => self.now() >= deadline,
+        }
+    }
+
+    /// The next deadline of a repeating schedule, None if this schedule runs only once.
+    fn next_deadline(&self) -> Option<f64> {
+        match self.when {
+            ScheduleWhen::EverySeconds(seconds) => Some(self.now() + seconds),
+            ScheduleWhen::EveryFrames(frames) => Some((Time::get_frame() + frames) as f64),
+            _ => None,
+        }
+    }
+}
+
+/// The handle of a task of the [`Scheduler`]. Dropping it doesn't cancel the task.
+#[derive(Debug, Clone)]
+pub struct TaskHandle {
+    pending: Rc<Cell<bool>>,
+}
+
+impl TaskHandle {
+    /// The task will not run anymore.
+    pub fn cancel(&self) {
+        self.pending.set(false)
+    }
+
+    /// false if the task has been cancelled or if it was a one shot task that has already run.
+    pub fn is_pending(&self) -> bool {
+        self.pending.get()
+    }
+}
+
+struct ScheduledTask {
+    schedule: Schedule,
+    deadline: f64,
+    handle: TaskHandle,
+    job: Box<dyn FnMut(&mut Sk)>,
+}
+
+/// Main thread scheduler running closures or pushing StepperActions after a delay, every N seconds, every N frames or
+/// at the next frame. The tasks run after the steppers and the Sk::execute_on_main closures, before the main
+/// application's Step callback.
+/// Get it with Sk::get_scheduler or SkInfo::get_scheduler.
+/// ```ignore
+/// let scheduler = sk_info.borrow().get_scheduler();
+/// // remove this stepper in 5 seconds
+/// let id = self.id.clone();
+/// scheduler.push_action(Schedule::after(5.0), StepperAction::remove(id));
+/// // blink every 0.5 real seconds
+/// let blink = scheduler.run(Schedule::every(0.5).unscaled(true), move |_sk| { /* ... */ });
+/// // later
+/// blink.cancel();
+/// ```
+#[derive(Default, Clone)]
+pub struct Scheduler {
+    tasks: Rc<RefCell<Vec<ScheduledTask>>>,
+}
+
+impl fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Scheduler with {} tasks", self.tasks.borrow().len())
+    }
+}
+
+impl Scheduler {
+    /// Run the closure according to the schedule.
+    pub fn run<F: FnMut(&mut Sk) + 'static>(&self, schedule: Schedule, job: F) -> TaskHandle {
+        let handle = TaskHandle { pending: Rc::new(Cell::new(true)) };
+        let task =
+            ScheduledTask { schedule, deadline: schedule.first_deadline(), handle: handle.clone(), job: Box::new(job) };
+        self.tasks.borrow_mut().push(task);
+        handle
+    }
+
+    /// Push the action to the steppers according to the schedule. As a StepperAction can be pushed only once,
+    /// repeating schedules are run only once.
+    pub fn push_action(&self, schedule: Schedule, action: StepperAction) -> TaskHandle {
+        let when = match schedule.when {
+            ScheduleWhen::EverySeconds(seconds) => ScheduleWhen::AfterSeconds(seconds),
+            ScheduleWhen::EveryFrames(frames) => ScheduleWhen::AfterFrames(frames),
+            other => other,
+        };
+        let mut action = Some(action);
+        self.run(Schedule { when, ..schedule }, move |sk| {
+            if let Some(action) = action.take() {
+                sk.push_action(action)
+            }
+        })
+    }
+
+    /// Cancel all the tasks.
+    pub fn clear(&self) {
+        for task in self.tasks.borrow_mut().drain(..) {
+            task.handle.cancel()
+        }
+    }
+
+    /// Run the tasks that are due. Tasks may schedule new tasks, they will be run at the earliest next frame.
+    pub(crate) fn step(&self, sk: &mut Sk) {
+        let due: Vec<ScheduledTask> = {
+            let mut tasks = self.tasks.borrow_mut();
+            tasks.retain(|task| task.handle.is_pending());
+            let (due, waiting) = tasks.drain(..).partition(|task| task.schedule.is_due(task.deadline));
+            *tasks = waiting;
+            due
+        };
+        for mut task in due {
+            if !task.handle.is_pending() {
+                continue;
+            }
+            (task.job)(sk);
+            match task.schedule.next_deadline() {
+                Some(deadline) if task.handle.is_pending() => {
+                    task.deadline = deadline;
+                    self.tasks.borrow_mut().push(task);
+                }
+                _ => task.handle.cancel(),
+            }
+        }
+    }
+}
+
+struct LocalTask {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    handle: TaskHandle,
+}
+
+/// Main thread executor polling its futures once per frame, after the [`Scheduler`] tasks. There is no waker: every
+/// pending future is polled at each frame, so the futures of [`Wait`] only check their condition when polled.
+/// Get it with Sk::get_executor or SkInfo::get_executor.
+/// ```ignore
+/// let executor = sk_info.borrow().get_executor();
+/// let tex = Tex::from_file("textures/parquet2/parquet2.ktx2", true, None).unwrap();
+/// executor.spawn_local(async move {
+///     Wait::seconds(2.0).await;
+///     if Wait::tex_loaded(tex).await == AssetState::Loaded {
+///         if let Some(file) = Wait::file_picker(PickerMode::Open, &Assets::TEXTURE_FORMATS).await {
+///             Log::info(format!("picked {}", file));
+///         }
+///     }
+/// });
+/// ```
+#[derive(Default, Clone)]
+pub struct Executor {
+    tasks: Rc<RefCell<Vec<LocalTask>>>,
+}
+
+/// A waker doing nothing: the pending futures are polled at each frame anyway.
+fn noop_waker() -> Waker {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RAW, |_| {}, |_| {}, |_| {});
+    const RAW: RawWaker = RawWaker::new(ptr::null(), &VTABLE);
+    // the functions of the vtable don't use the data pointer.
+    unsafe { Waker::from_raw(RAW) }
+}
+
+impl fmt::Debug for Executor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Executor with {} tasks", self.tasks.borrow().len())
+    }
+}
+
+impl Executor {
+    /// Add a future to poll. The returned handle can cancel it, dropping the future at the next frame.
+    pub fn spawn_local<F: Future<Output = ()> + 'static>(&self, future: F) -> TaskHandle {
+        let handle = TaskHandle { pending: Rc::new(Cell::new(true)) };
+        self.tasks.borrow_mut().push(LocalTask { future: Box::pin(future), handle: handle.clone() });
+        handle
+    }
+
+    /// Call the closure on the main thread once the asset is loaded, or with the error if it failed to load.
+    /// ```ignore
+    /// executor.on_loaded(Model::from_file_async("mobiles.gltf", None), |model| match model {
+    ///     Ok(model) => { /* ... */ }
+    ///     Err(error) => Log::warn(format!("{}", error)),
+    /// });
+    /// ```
+    pub fn on_loaded<T, F>(&self, load: AssetLoad<T>, on_loaded: F) -> TaskHandle
+    where
+        T: IAsset + 'static,
+        F: FnOnce(Result<T, StereoKitError>) + 'static,
+    {
+        self.spawn_local(async move { on_loaded(load.await) })
+    }
+
+    /// The number of futures not yet completed nor cancelled.
+    pub fn get_pending_count(&self) -> usize {
+        self.tasks.borrow().iter().filter(|task| task.handle.is_pending()).count()
+    }
+
+    /// Cancel all the futures.
+    pub fn clear(&self) {
+        for task in self.tasks.borrow_mut().drain(..) {
+            task.handle.cancel()
+        }
+    }
+
+    /// Poll all the pending futures once. Futures spawned while polling are polled at the next frame.
+    pub(crate) fn step(&self) {
+        let tasks: Vec<LocalTask> = self.tasks.borrow_mut().drain(..).collect();
+        let waker = noop_waker();
+        let mut context = Context::from_waker(&waker);
+        let mut still_pending = Vec::with_capacity(tasks.len());
+        for mut task in tasks {
+            if !task.handle.is_pending() {
+                continue;
+            }
+            match task.future.as_mut().poll(&mut context) {
+                Poll::Ready(()) => task.handle.cancel(),
+                Poll::Pending => still_pending.push(task),
+            }
+        }
+        let mut tasks = self.tasks.borrow_mut();
+        still_pending.append(&mut tasks);
+        *tasks = still_pending;
+    }
+}
+
+/// Futures to await in the tasks of the [`Executor`].
+pub struct Wait;
+
+impl Wait {
+    /// Wait for the next frame.
+    pub fn next_frame() -> impl Future<Output = ()> {
+        Self::frames(1)
+    }
+
+    /// Wait for `frames` frames.
+    pub fn frames(frames: u64) -> impl Future<Output = ()> {
+        let frame = Time::get_frame() + frames;
+        PollFn(move || (Time::get_frame() >= frame).then_some(()))
+    }
+
+    /// Wait for `seconds` of the scaled time (Time::get_total).
+    pub fn seconds(seconds: f64) -> impl Future<Output = ()> {
+        let deadline = Time::get_total() + seconds;
+        PollFn(move || (Time::get_total() >= deadline).then_some(()))
+    }
+
+    /// Wait for `seconds` of the real time (Time::get_total_unscaled).
+    pub fn seconds_unscaled(seconds: f64) -> impl Future<Output = ()> {
+        let deadline = Time::get_total_unscaled() + seconds;
+        PollFn(move || (Time::get_total_unscaled() >= deadline).then_some(()))
+    }
+
+    /// Wait until `get_state` returns AssetState::Loaded or an error state, and return that state. To get the asset or
+    /// a StereoKitError, await an [`AssetLoad`] instead.
+    pub fn asset_loaded<F: FnMut() -> AssetState>(mut get_state: F) -> impl Future<Output = AssetState> {
+        PollFn(move || {
+            let state = get_state();
+            state.is_finished().then_some(state)
+        })
+    }
+
+    /// Wait until the texture is loaded or failed to load, and return its final state. Give a Tex or an Rc<Tex>.
+    pub fn tex_loaded<T: AsRef<Tex>>(tex: T) -> impl Future<Output = AssetState> {
+        Self::asset_loaded(move || tex.as_ref().get_asset_state())
+    }
+
+    /// Open a file picker and wait for the user. Return the selected file or None if the picker has been cancelled.
+    /// Only one file picker can be opened at a time, None is returned at once if one is already visible.
+    pub fn file_picker(mode: PickerMode, filters: &[impl AsRef<str>]) -> impl Future<Output = Option<String>> {
+        let filters: Vec<String> = filters.iter().map(|filter| filter.as_ref().to_string()).collect();
+        let mut started = false;
+        PollFn(move || {
+            if !started {
+                started = true;
+                if Platform::get_file_picker_visible() {
+                    return Some(None);
+                }
+                *PICKED_FILE.lock().unwrap() = None;
+                // the closure must not capture anything as the picker may call it after this function returns
+                Platform::file_picker_sz(
+                    mode,
+                    |ok, file_name| *PICKED_FILE.lock().unwrap() = Some(ok.then(|| file_name.to_string())),
+                    &filters,
+                );
+            }
+            PICKED_FILE.lock().unwrap().take()
+        })
+    }
+}
+
+/// The result of the file picker opened by Wait::file_picker.
+static PICKED_FILE: Mutex<Option<Option<String>>> = Mutex::new(None);
+
+/// A future ready when the closure returns Some.
+struct PollFn<F>(F);
+
+// The closure is never pinned
+impl<F> Unpin for PollFn<F> {}
+
+impl<T, F: FnMut() -> Option<T>> Future for PollFn<F> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+        match (self.0)() {
+            Some(value) => Poll::Ready(value),
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// The CPU time spent in the step of a stepper or of the app, measured by the [`Profiler`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepperTiming {
+    pub id: StepperId,
+    /// The duration of the last frame.
+    pub last: Duration,
+    /// Mean over the first [`Profiler::AVERAGE_FRAMES`] frames, then moving average over about that many frames.
+    pub average: Duration,
+    /// The longest duration since the stepper was added or the profiler reset.
+    pub max: Duration,
+    /// The number of frames measured.
+    pub samples: u64,
+}
+
+impl StepperTiming {
+    fn new(id: &str) -> Self {
+        Self { id: id.to_string(), last: Duration::ZERO, average: Duration::ZERO, max: Duration::ZERO, samples: 0 }
+    }
+
+    fn add(&mut self, duration: Duration) {
+        self.samples += 1;
+        let window = self.samples.min(Profiler::AVERAGE_FRAMES) as f64;
+        let average = self.average.as_secs_f64();
+        self.average = Duration::from_secs_f64(average + (duration.as_secs_f64() - average) / window);
+        self.max = self.max.max(duration);
+        self.last = duration;
+    }
+}
+
+#[derive(Default)]
+struct ProfilerData {
+    enabled: bool,
+    timings: Vec<StepperTiming>,
+}
+
+/// Measure the CPU time spent by each stepper in IStepper::step and by the app's on_step closure. Disabled by default
+/// as measuring has a small cost. See [`crate::tools::profiler_window::ProfilerWindow`] to display the timings.
+/// Get it with Sk::get_profiler or SkInfo::get_profiler.
+/// ```ignore
+/// let profiler = sk_info.borrow().get_profiler();
+/// profiler.enable(true);
+/// // later
+/// for timing in profiler.get_timings() {
+///     Log::diag(format!("{} : {:?} / {:?}", timing.id, timing.average, timing.max));
+/// }
+/// ```
+#[derive(Default, Clone)]
+pub struct Profiler {
+    data: Rc<RefCell<ProfilerData>>,
+}
+
+impl fmt::Debug for Profiler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = self.data.borrow();
+        write!(f, "Profiler enabled:{} with {} timings", data.enabled, data.timings.len())
+    }
+}
+
+impl Profiler {
+    /// The id of the timing of the app's on_step closure.
+    pub const APP_STEP_ID: &'static str = "app on_step";
+    /// The number of frames of the moving average.
+    pub const AVERAGE_FRAMES: u64 = 60;
+
+    /// Start or stop measuring. The timings already measured are kept.
+    pub fn enable(&self, enabled: bool) {
+        self.data.borrow_mut().enabled = enabled;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.data.borrow().enabled
+    }
+
+    /// The timings, the most expensive on average first.
+    pub fn get_timings(&self) -> Vec<StepperTiming> {
+        let mut timings = self.data.borrow().timings.clone();
+        timings.sort_by_key(|timing| Reverse(timing.average));
+        timings
+    }
+
+    /// The timing of one stepper, or of the app with [`Profiler::APP_STEP_ID`].
+    pub fn get_timing(&self, id: impl AsRef<str>) -> Option<StepperTiming> {
+        self.data.borrow().timings.iter().find(|timing| timing.id == id.as_ref()).cloned()
+    }
+
+    /// Forget all the timings.
+    pub fn reset(&self) {
+        self.data.borrow_mut().timings.clear();
+    }
+
+    /// Run f, measuring it under the id if the profiler is enabled.
+    pub(crate) fn measure<R>(&self, id: &str, f: impl FnOnce() -> R) -> R {
+        if !self.is_enabled() {
+            return f();
+        }
+        let start = Instant::now();
+        let result = f();
+        let duration = start.elapsed();
+        let mut data = self.data.borrow_mut();
+        match data.timings.iter_mut().find(|timing| timing.id == id) {
+            Some(timing) => timing.add(duration),
+            None => {
+                let mut timing = StepperTiming::new(id);
+                timing.add(duration);
+                data.timings.push(timing);
+            }
+        }
+        result
+    }
+
+    pub(crate) fn remove(&self, id: &str) {
+        self.data.borrow_mut().timings.retain(|timing| timing.id != id);
     }
 }
 
@@ -367,3 +2168,99 @@ impl<'a> StepperClosures<'a> {
         (self.shutdown)()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Dummy;
+
+    impl IStepper for Dummy {
+        fn initialize(&mut self, _id: StepperId, _sk: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
+            Ok(())
+        }
+
+        fn step(&mut self, _token: &MainThreadToken) {}
+    }
+
+    /// The text and count params given to the Dummy factory.
+    type Created = Rc<RefCell<Vec<(String, i32)>>>;
+
+    /// A registry with a Dummy factory keeping the params it was given.
+    fn dummy_registry() -> (StepperRegistry, Created) {
+        let registry = StepperRegistry::default();
+        let created = Rc::new(RefCell::new(vec![]));
+        let created_by_factory = created.clone();
+        registry.register("Dummy", move |params| {
+            created_by_factory
+                .borrow_mut()
+                .push((params.get_or("text", String::new()), params.get_or("count", 0)));
+            Ok(Dummy)
+        });
+        (registry, created)
+    }
+
+    #[test]
+    fn parse_scene() {
+        let (registry, created) = dummy_registry();
+        let scene = registry
+            .scene_from_str(
+                r#"
+                name = "Level"
+                fade = 2
+                overlay = true
+
+                [[stepper]]
+                type = "Dummy"
+                id = "first"
+                [stepper.params]
+                text = "hello"
+                count = 3
+
+                [[stepper]]
+                type = "Dummy"
+                "#,
+            )
+            .unwrap();
+        assert_eq!(scene.name, "Level");
+        assert!(matches!(scene.transition, SceneTransition::Fade { seconds, .. } if seconds == 2.0));
+        assert!(scene.overlay);
+        let ids: Vec<&str> = scene.steppers.iter().map(|(_, _, id)| id.as_str()).collect();
+        assert_eq!(ids, ["first", "Dummy"]);
+        assert!(scene.steppers.iter().all(|(_, type_id, _)| *type_id == TypeId::of::<Dummy>()));
+        assert_eq!(*created.borrow(), [("hello".to_string(), 3), (String::new(), 0)]);
+
+        let scene = registry.scene_from_str("").unwrap();
+        assert_eq!(scene.name, "scene");
+        assert!(matches!(scene.transition, SceneTransition::None));
+        assert!(!scene.overlay && scene.steppers.is_empty());
+    }
+
+    #[test]
+    fn scene_errors() {
+        let (registry, _) = dummy_registry();
+        for text in
+            ["name = ", "stepper = 1", "[[stepper]]\nid = \"a\"", "[[stepper]]\ntype = \"Dummy\"\nparams.a = [1]"]
+        {
+            assert!(matches!(registry.scene_from_str(text), Err(StereoKitError::SceneFile(..))), "{}", text);
+        }
+        assert!(matches!(
+            registry.scene_from_str("[[stepper]]\ntype = \"Unknown\""),
+            Err(StereoKitError::StepperFactory(name, _)) if name == "Unknown"
+        ));
+    }
+
+    #[test]
+    fn load_sample_scene() {
+        let (registry, created) = dummy_registry();
+        registry.register("HudNotification", |_params| Ok(Dummy));
+        let scene = registry.load_scene("assets/scenes/demo.toml").unwrap();
+        assert_eq!(scene.name, "Demo");
+        assert_eq!(scene.steppers.len(), 1);
+        assert!(created.borrow().is_empty());
+        assert!(matches!(
+            registry.load_scene("assets/scenes/missing.toml"),
+            Err(StereoKitError::SceneFile(path, _)) if path.ends_with("missing.toml")
+        ));
+    }
+}
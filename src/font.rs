@@ -4,7 +4,12 @@ use std::{
     ptr::NonNull,
 };
 
-use crate::{system::IAsset, tex::TexT, StereoKitError};
+use crate::{
+    system::{Asset, AssetType, IAsset},
+    tex::TexT,
+    vfs::Vfs,
+    StereoKitError,
+};
 
 /// This class represents a text font asset! On the back-end, this asset is composed of a texture with font characters
 /// rendered to it, and a list of data about where, and how large those characters are on the texture.
@@ -47,13 +52,26 @@ extern "C" {
 }
 
 impl IAsset for Font {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Font;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Font::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Font(font) => Some(font),
+            _ => None,
+        }
+    }
 }
 
 impl Default for Font {
@@ -87,6 +105,19 @@ impl Font {
         ))
     }
 
+    /// Non canonical function. Loads a font from a [`Vfs`] (directories, zip archives or bundles). The fonts of the
+    /// archives and bundles are extracted first, see [`Vfs::get_file`]. The asset id is the virtual path (see
+    /// [`Vfs::virtual_path`]), the font already loaded with this id is returned if any.
+    pub fn from_vfs(vfs: &Vfs, path: impl AsRef<Path>) -> Result<Font, StereoKitError> {
+        let id = Vfs::virtual_path(path);
+        if let Ok(font) = Self::find(&id) {
+            return Ok(font);
+        }
+        let mut font = Self::from_file(vfs.get_file(&id)?)?;
+        font.id(&id);
+        Ok(font)
+    }
+
     /// Loads a font and creates a font asset from it.
     /// If a glyph is not found, StereoKit will look in the next font file in the list.
     /// <https://stereokit.net/Pages/StereoKit/Font/FromFile.html>
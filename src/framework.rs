@@ -1,7 +1,7 @@
 use std::{borrow::BorrowMut, cell::RefCell, collections::VecDeque, rc::Rc};
 
 use crate::{
-    event_loop::{IStepper, StepperId},
+    event_loop::{IStepper, StepperId, StepperOrder, StepperParams, StepperPhase},
     material::Material,
     maths::{lerp, units::CM, Matrix, Plane, Pose, Quat, Vec2, Vec3},
     mesh::{Inds, Mesh, Vertex},
@@ -17,6 +17,7 @@ use crate::{
         named_colors::{GREEN, WHITE},
         Color128, Time,
     },
+    StereoKitError,
 };
 
 /// StereoKit initialization settings! Setup SkSettings with your data before calling SkSetting.Init().
@@ -366,15 +367,13 @@ pub struct HandMenuRadial {
     pub text_style: TextStyle,
 }
 
-unsafe impl Send for HandMenuRadial {}
-
 impl IStepper for HandMenuRadial {
     /// Part of IStepper, you shouldn’t be calling this yourself.
     /// <https://stereokit.net/Pages/StereoKit.Framework/HandMenuRadial/Initialize.html>
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
-        true
+        Ok(())
     }
 
     /// Part of IStepper, you shouldn’t be calling this yourself.
@@ -392,6 +391,22 @@ impl IStepper for HandMenuRadial {
     /// Part of IStepper, you shouldn’t be calling this yourself.
     /// <https://stereokit.net/Pages/StereoKit.Framework/HandMenuRadial/Shutdown.html>
     fn shutdown(&mut self) {}
+
+    /// The menu is drawn with the other windows, once everything has moved.
+    fn order(&self) -> StepperOrder {
+        StepperOrder::new(StepperPhase::Ui)
+    }
+
+    /// The check state of the Checked and Unchecked items, by path of names ("Layer/Item").
+    fn save_state(&self) -> Option<StepperParams> {
+        let mut state = StepperParams::new();
+        Self::save_checks(&self.root, "", &mut state);
+        Some(state)
+    }
+
+    fn load_state(&mut self, state: &StepperParams) {
+        Self::load_checks(&self.root, "", state);
+    }
 }
 
 impl HandMenuRadial {
@@ -712,46 +727,7 @@ impl HandMenuRadial {
                 self.last_selected = item_selected.clone();
                 self.last_selected_time = Time::get_total_unscaledf();
 
-                if let Some(group_to_change) = item_selected.as_ref().is_unchecked_action() {
-                    for line in layer.items().iter() {
-                        if let Some(group) = line.as_ref().is_checked_action() {
-                            if group == group_to_change {
-                                let mut to_reverse = line.as_ref();
-                                let to_to_reverse = to_reverse.borrow_mut();
-
-                                if let HandRadial::Item(menu_item) = to_to_reverse {
-                                    menu_item.action.replace(HandMenuAction::Unchecked(group));
-                                }
-                            }
-                        }
-                    }
-                    let mut to_reverse = item_selected.as_ref();
-                    let to_to_reverse = to_reverse.borrow_mut();
-                    if let HandRadial::Item(menu_item) = to_to_reverse {
-                        menu_item.action.replace(HandMenuAction::Checked(group_to_change));
-                    }
-                } else if let Some(group_to_change) = item_selected.as_ref().is_checked_action() {
-                    // If there is only one of this group this is a toggle button
-                    let mut cpt = 0;
-                    for line in layer.items().iter() {
-                        if let Some(group) = line.as_ref().is_checked_action() {
-                            if group_to_change == group {
-                                cpt += 1
-                            }
-                        } else if let Some(group) = line.as_ref().is_unchecked_action() {
-                            if group_to_change == group {
-                                cpt += 1
-                            }
-                        }
-                    }
-                    if cpt == 1 {
-                        let mut to_reverse = item_selected.as_ref();
-                        let to_to_reverse = to_reverse.borrow_mut();
-                        if let HandRadial::Item(menu_item) = to_to_reverse {
-                            menu_item.action.replace(HandMenuAction::Unchecked(group_to_change));
-                        }
-                    }
-                }
+                Self::apply_check(layer, item_selected);
 
                 self.select_item(item_selected.clone(), tip_world, ((angle_id as f32) + 0.5) * step)
             } else {
@@ -815,6 +791,100 @@ impl HandMenuRadial {
         );
     }
 
+    /// Check or uncheck the item as if the user has selected it: a selected unchecked item is checked and unchecks the
+    /// other items of its group, a checked item alone in its group is unchecked.
+    fn apply_check(layer: &HandRadial, item_selected: &Rc<HandRadial>) {
+        if let Some(group_to_change) = item_selected.as_ref().is_unchecked_action() {
+            for line in layer.items().iter() {
+                if let Some(group) = line.as_ref().is_checked_action() {
+                    if group == group_to_change {
+                        let mut to_reverse = line.as_ref();
+                        let to_to_reverse = to_reverse.borrow_mut();
+
+                        if let HandRadial::Item(menu_item) = to_to_reverse {
+                            menu_item.action.replace(HandMenuAction::Unchecked(group));
+                        }
+                    }
+                }
+            }
+            let mut to_reverse = item_selected.as_ref();
+            let to_to_reverse = to_reverse.borrow_mut();
+            if let HandRadial::Item(menu_item) = to_to_reverse {
+                menu_item.action.replace(HandMenuAction::Checked(group_to_change));
+            }
+        } else if let Some(group_to_change) = item_selected.as_ref().is_checked_action() {
+            // If there is only one of this group this is a toggle button
+            let mut cpt = 0;
+            for line in layer.items().iter() {
+                if let Some(group) = line.as_ref().is_checked_action() {
+                    if group_to_change == group {
+                        cpt += 1
+                    }
+                } else if let Some(group) = line.as_ref().is_unchecked_action() {
+                    if group_to_change == group {
+                        cpt += 1
+                    }
+                }
+            }
+            if cpt == 1 {
+                let mut to_reverse = item_selected.as_ref();
+                let to_to_reverse = to_reverse.borrow_mut();
+                if let HandRadial::Item(menu_item) = to_to_reverse {
+                    menu_item.action.replace(HandMenuAction::Unchecked(group_to_change));
+                }
+            }
+        }
+    }
+
+    /// The key of an item in the saved state: the names of its layers and its own, separated by '/'.
+    fn check_key(path: &str, name: &str) -> String {
+        let name = name.split_whitespace().collect::<Vec<&str>>().join(" ");
+        if path.is_empty() {
+            name
+        } else {
+            format!("{}/{}", path, name)
+        }
+    }
+
+    fn save_checks(layer: &HandRadial, path: &str, state: &mut StepperParams) {
+        for line in layer.items() {
+            let key = Self::check_key(path, line.get_name());
+            match line.as_ref() {
+                HandRadial::Layer(_) => Self::save_checks(line, &key, state),
+                HandRadial::Item(item) => match *item.action.borrow() {
+                    HandMenuAction::Checked(_) => {
+                        state.set(key, true);
+                    }
+                    HandMenuAction::Unchecked(_) => {
+                        state.set(key, false);
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    /// Restore the saved checks. The callback of an item whose check changes is called, as if the user has selected it,
+    /// so the app is in the state the menu shows.
+    fn load_checks(layer: &HandRadial, path: &str, state: &StepperParams) {
+        for line in layer.items() {
+            let key = Self::check_key(path, line.get_name());
+            match line.as_ref() {
+                HandRadial::Layer(_) => Self::load_checks(line, &key, state),
+                HandRadial::Item(item) => {
+                    let Some(checked) = state.get::<bool>(&key) else { continue };
+                    if line.is_checked_action().is_some() != checked {
+                        Self::apply_check(layer, line);
+                        if line.is_checked_action().is_some() == checked {
+                            let mut callback = item.callback.borrow_mut();
+                            callback()
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn select_item(&mut self, line: Rc<HandRadial>, at: Vec3, from_angle: f32) {
         match line.as_ref() {
             HandRadial::Item(item) => {
@@ -1,11 +1,11 @@
 use std::{ffi::NulError, path::PathBuf};
-pub use stereokit_macros::include_asset_tree;
+pub use stereokit_macros::{asset_path, include_asset_bundle, include_asset_tree, include_assets};
 use thiserror::Error;
 pub mod anchor;
-#[cfg(feature = "event-loop")]
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
 pub mod event_loop;
 pub mod font;
-#[cfg(feature = "event-loop")]
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
 pub mod framework;
 pub mod material;
 pub mod maths;
@@ -18,10 +18,11 @@ pub mod sound;
 pub mod sprite;
 pub mod system;
 pub mod tex;
-#[cfg(feature = "event-loop")]
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
 pub mod tools;
 pub mod ui;
 pub mod util;
+pub mod vfs;
 
 #[derive(Error, Debug)]
 pub enum StereoKitError {
@@ -83,6 +84,28 @@ pub enum StereoKitError {
     SoundCreate(String),
     #[error("failed to find anchor {0} for reason {1}")]
     AnchorFind(String, String),
+    #[error("stepper {0} failed to initialize: {1}")]
+    StepperInit(String, String),
+    #[error("cycle in the order constraints of steppers {0}")]
+    StepperCycle(String),
+    #[error("failed to create stepper {0} for reason {1}")]
+    StepperFactory(String, String),
+    #[error("failed to load scene file {0} for reason {1}")]
+    SceneFile(PathBuf, String),
+    #[error("failed to read or write the steppers state file {0} for reason {1}")]
+    StepperState(PathBuf, String),
+    #[error("failed to read or write the input recording {0} for reason {1}")]
+    InputRecord(PathBuf, String),
+    #[error("asset {0} not found at {1}")]
+    AssetNotFound(String, PathBuf),
+    #[error("asset {0} from {1} has an unsupported format")]
+    AssetUnsupported(String, PathBuf),
+    #[error("asset {0} from {1} failed to load")]
+    AssetLoad(String, PathBuf),
+    #[error("failed to load asset manifest {0} for reason {1}")]
+    AssetManifest(PathBuf, String),
+    #[error("failed to read {0} from the virtual file system for reason {1}")]
+    Vfs(String, String),
     #[error("failed to init stereokit with settings {0}")]
     SkInit(String),
     #[cfg(feature = "event-loop")]
@@ -5,7 +5,7 @@ use std::ptr::NonNull;
 
 use crate::maths::{Bool32T, Matrix, Vec2, Vec3, Vec4};
 use crate::shader::{Shader, ShaderT};
-use crate::system::{IAsset, Log};
+use crate::system::{Asset, AssetType, IAsset, Log};
 use crate::tex::{Tex, TexT};
 use crate::util::Color128;
 use crate::StereoKitError;
@@ -128,13 +128,26 @@ extern "C" {
 }
 
 impl IAsset for Material {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Material;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Material::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Material(material) => Some(material),
+            _ => None,
+        }
+    }
 }
 
 impl Default for Material {
@@ -7,7 +7,7 @@ use crate::{
     material::{Cull, Material, MaterialT},
     maths::{Bool32T, Bounds, Matrix, Ray, Vec2, Vec3, Vec4},
     sk::MainThreadToken,
-    system::{IAsset, RenderLayer},
+    system::{Asset, AssetType, IAsset, RenderLayer},
     util::{Color128, Color32},
     StereoKitError,
 };
@@ -189,13 +189,26 @@ extern "C" {
 }
 
 impl IAsset for Mesh {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Mesh;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Mesh::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Mesh(mesh) => Some(mesh),
+            _ => None,
+        }
+    }
 }
 
 impl Default for Mesh {
@@ -5,8 +5,9 @@ use crate::{
     maths::{Bounds, Ray, Vec3},
     mesh::{Mesh, MeshT},
     shader::{Shader, ShaderT},
-    system::{IAsset, Log, RenderLayer},
+    system::{Asset, AssetLoad, AssetType, IAsset, Log, RenderLayer},
     util::Color128,
+    vfs::Vfs,
     StereoKitError,
 };
 use std::{
@@ -125,13 +126,26 @@ extern "C" {
 }
 
 impl IAsset for Model {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Model;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Model::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Model(model) => Some(model),
+            _ => None,
+        }
+    }
 }
 
 impl Default for Model {
@@ -198,6 +212,30 @@ impl Model {
             None => Err(StereoKitError::ModelFromFile(path_buf.to_owned(), "file not found!".to_owned())),
         }
     }
+
+    /// Non canonical function. Same as from_file, returned as an [`AssetLoad`] so models can be waited for like the
+    /// other assets. The model is loaded synchronously, its textures may still be loading when it is ready.
+    pub fn from_file_async(file_utf8: impl AsRef<Path>, shader: Option<Shader>) -> AssetLoad<Model> {
+        AssetLoad::new(Self::from_file(&file_utf8, shader), file_utf8)
+    }
+
+    /// Non canonical function. Loads a model from a [`Vfs`] (directories, zip archives or bundles). The files of the
+    /// mounted directories are loaded as files so their references to other files work, the other ones are loaded from
+    /// memory. The asset id is the virtual path (see [`Vfs::virtual_path`]), the model already loaded with this id is
+    /// returned if any.
+    pub fn from_vfs(vfs: &Vfs, path: impl AsRef<Path>, shader: Option<Shader>) -> Result<Model, StereoKitError> {
+        let id = Vfs::virtual_path(path);
+        if let Ok(model) = Self::find(&id) {
+            return Ok(model);
+        }
+        let mut model = match vfs.get_dir_file(&id) {
+            Some(file) => Self::from_file(file, shader)?,
+            None => Self::from_memory(&id, &vfs.read(&id)?, shader)?,
+        };
+        model.id(&id);
+        Ok(model)
+    }
+
     /// Creates a new Model from an existing one.
     /// <https://stereokit.net/Pages/StereoKit/Model/Copy.html>
     ///
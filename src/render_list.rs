@@ -1,11 +1,17 @@
-use std::{self, ffi::c_void, ptr::NonNull};
+use std::{
+    self,
+    ffi::{c_void, CStr, CString},
+    ptr::NonNull,
+};
 
 use crate::{
     material::{Material, MaterialT},
     maths::{Matrix, Rect},
     mesh::{Mesh, MeshT},
     model::{Model, ModelT},
-    system::{assets_releaseref_threadsafe, RenderClear, RenderLayer},
+    system::{
+        asset_get_id, asset_set_id, assets_releaseref_threadsafe, Asset, AssetType, IAsset, RenderClear, RenderLayer,
+    },
     tex::{Tex, TexT},
     util::Color128,
 };
@@ -81,6 +87,25 @@ extern "C" {
 
 }
 
+impl IAsset for RenderList {
+    const ASSET_TYPE: AssetType = AssetType::RenderList;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
+
+    fn get_id(&self) -> &str {
+        self.get_id()
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::RenderList(render_list) => Some(render_list),
+            _ => None,
+        }
+    }
+}
+
 impl Default for RenderList {
     fn default() -> Self {
         Self::new()
@@ -96,6 +121,23 @@ impl RenderList {
         RenderList(NonNull::new(unsafe { render_list_create() }).unwrap())
     }
 
+    /// Set a new id to the render list. There is no native find for render lists, use IAsset::find or
+    /// Assets::find::<RenderList> to get it back.
+    ///
+    /// see also [`crate::system::asset_set_id`]
+    pub fn id<S: AsRef<str>>(&mut self, id: S) -> &mut Self {
+        let c_str = CString::new(id.as_ref()).unwrap();
+        unsafe { asset_set_id(self.0.as_ptr() as *mut c_void, c_str.as_ptr()) };
+        self
+    }
+
+    /// The id of the render list.
+    ///
+    /// see also [`crate::system::asset_get_id`]
+    pub fn get_id(&self) -> &str {
+        unsafe { CStr::from_ptr(asset_get_id(self.0.as_ptr() as *mut c_void)) }.to_str().unwrap()
+    }
+
     /// The number of Mesh/Material pairs that have been submitted to the render list so far this frame.
     /// <https://stereokit.net/Pages/StereoKit/RenderList/Count.html>
     ///
@@ -1,4 +1,8 @@
-use crate::{system::IAsset, StereoKitError};
+use crate::{
+    system::{Asset, AssetType, IAsset},
+    vfs::Vfs,
+    StereoKitError,
+};
 use std::{
     ffi::{c_void, CStr, CString},
     path::Path,
@@ -41,13 +45,26 @@ extern "C" {
 }
 
 impl IAsset for Shader {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Shader;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Shader::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Shader(shader) => Some(shader),
+            _ => None,
+        }
+    }
 }
 
 /// This is a fast, general purpose shader. It uses a texture for ‘diffuse’, a ‘color’ property for tinting the
@@ -91,6 +108,19 @@ impl Shader {
         ))
     }
 
+    /// Non canonical function. Loads a precompiled StereoKit Shader (.sks) from a [`Vfs`] (directories, zip archives
+    /// or bundles). The asset id is the virtual path (see [`Vfs::virtual_path`]), the shader already loaded with this id
+    /// is returned if any.
+    pub fn from_vfs(vfs: &Vfs, path: impl AsRef<Path>) -> Result<Shader, StereoKitError> {
+        let id = Vfs::virtual_path(path);
+        if let Ok(shader) = Self::find(&id) {
+            return Ok(shader);
+        }
+        let mut shader = Self::from_memory(&vfs.read(&id)?)?;
+        shader.id(&id);
+        Ok(shader)
+    }
+
     /// Looks for a shader asset that’s already loaded, matching the given id!
     /// <https://stereokit.net/Pages/StereoKit/Shader/Find.html>
     ///
@@ -1,5 +1,5 @@
 use crate::{maths::Bool32T, system::LogLevel};
-use crate::{system::Log, StereoKitError};
+use crate::{system::Log, vfs::Vfs, StereoKitError};
 use std::{
     cell::RefCell,
     ffi::{c_char, c_void, CStr, CString},
@@ -16,10 +16,20 @@ use winit::platform::android::activity::AndroidApp;
 #[cfg(feature = "no-event-loop")]
 use android_activity::AndroidApp;
 
-#[cfg(feature = "event-loop")]
-use crate::event_loop::{StepperAction, Steppers};
-#[cfg(feature = "event-loop")]
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+use crate::event_loop::{
+    Executor, IStepper, Profiler, Scene, Schedule, Scheduler, StepperAction, StepperId, StepperInfo, StepperRegistry,
+    Steppers, TaskHandle,
+};
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+use crate::tools::{asset_cache::AssetCache, asset_leaks::AssetLeaks};
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
 use std::collections::VecDeque;
+#[cfg(feature = "no-event-loop")]
+#[cfg(not(feature = "event-loop"))]
+use std::sync::mpsc;
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+use std::{any::Any, future::Future};
 #[cfg(feature = "event-loop")]
 use winit::{
     event::Event,
@@ -548,6 +558,22 @@ pub struct SkInfo {
     system_info: SystemInfo,
     #[cfg(feature = "event-loop")]
     event_loop_proxy: Option<EventLoopProxy<StepperAction>>,
+    #[cfg(feature = "no-event-loop")]
+    #[cfg(not(feature = "event-loop"))]
+    action_sender: mpsc::Sender<StepperAction>,
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    scheduler: Scheduler,
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    executor: Executor,
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    profiler: Profiler,
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    stepper_registry: StepperRegistry,
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    asset_cache: AssetCache,
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    asset_leaks: AssetLeaks,
+    vfs: Vfs,
     #[cfg(target_os = "android")]
     android_app: AndroidApp,
 }
@@ -559,6 +585,63 @@ impl SkInfo {
         self.event_loop_proxy.clone()
     }
 
+    /// Get a sender to push some StepperActions to the steppers from any thread. This is the no-event-loop
+    /// counterpart of the event_loop_proxy, the actions are read at the start of each Sk::step_looped.
+    #[cfg(feature = "no-event-loop")]
+    #[cfg(not(feature = "event-loop"))]
+    pub fn get_action_sender(&self) -> mpsc::Sender<StepperAction> {
+        self.action_sender.clone()
+    }
+
+    /// Get a handle to the main thread scheduler to run some code later. See [`Scheduler`]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    pub fn get_scheduler(&self) -> Scheduler {
+        self.scheduler.clone()
+    }
+
+    /// Get a handle to the main thread executor to spawn some futures. See [`Executor`]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    pub fn get_executor(&self) -> Executor {
+        self.executor.clone()
+    }
+
+    /// Register a stepper from another stepper. The stepper doesn't have to be Send, it's handed to Sk::add_stepper by
+    /// the scheduler at the next frame.
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    pub fn add_stepper<T: IStepper>(&self, stepper_id: impl AsRef<str>, stepper: T) {
+        let stepper_id = stepper_id.as_ref().to_string();
+        let mut stepper = Some(stepper);
+        self.scheduler.run(Schedule::next_frame(), move |sk| {
+            if let Some(stepper) = stepper.take() {
+                sk.add_stepper(&stepper_id, stepper)
+            }
+        });
+    }
+
+    /// Get a handle to the profiler of the steppers. See [`Profiler`]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    pub fn get_profiler(&self) -> Profiler {
+        self.profiler.clone()
+    }
+
+    /// Get a handle to the named stepper factories. See [`StepperRegistry`]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    pub fn get_stepper_registry(&self) -> StepperRegistry {
+        self.stepper_registry.clone()
+    }
+
+    /// Get a handle to the cache of the assets loaded from files. See [`AssetCache`]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    pub fn get_asset_cache(&self) -> AssetCache {
+        self.asset_cache.clone()
+    }
+
+    /// Get a handle to the asset leak detection. See [`AssetLeaks`]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+    pub fn get_asset_leaks(&self) -> AssetLeaks {
+        self.asset_leaks.clone()
+    }
+
     /// This is a copy of the settings that StereoKit was initialized with, so you can refer back to them a little
     /// easier. These are read only, and keep in mind that some settings are only requests! Check Sk.system and other
     /// properties for the current state of StereoKit.
@@ -574,6 +657,27 @@ impl SkInfo {
         self.system_info.clone()
     }
 
+    /// Get a handle to the virtual file system of the app, with the assets mounted at its root. See [`Vfs`]
+    pub fn get_vfs(&self) -> Vfs {
+        self.vfs.clone()
+    }
+
+    /// The Vfs with SkSettings::assets_folder mounted at the root.
+    #[cfg(not(target_os = "android"))]
+    fn assets_vfs(settings: &SkSettings) -> Vfs {
+        let assets_folder = match settings.assets_folder.is_null() {
+            true => "",
+            false => unsafe { CStr::from_ptr(settings.assets_folder) }.to_str().unwrap_or(""),
+        };
+        Vfs::with_assets_folder(assets_folder)
+    }
+
+    /// The Vfs with the assets of the APK mounted at the root, extracting the files in the internal data path.
+    #[cfg(target_os = "android")]
+    fn assets_vfs(app: &AndroidApp) -> Vfs {
+        Vfs::with_android_assets(app.asset_manager(), app.internal_data_path().map(|path| path.join("vfs")))
+    }
+
     /// Non canonical function to get the rust ndk AndroidApp
     #[cfg(target_os = "android")]
     pub fn get_android_app(&mut self) -> &AndroidApp {
@@ -583,16 +687,29 @@ impl SkInfo {
 
 /// A token you only find on the main thread. It is required to call rendering functions
 pub struct MainThreadToken {
-    #[cfg(feature = "event-loop")]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
     pub(crate) event_report: Vec<StepperAction>,
 }
 
-#[cfg(feature = "event-loop")]
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
 impl MainThreadToken {
     /// Get the event_report of this step
     pub fn get_event_report(&self) -> &Vec<StepperAction> {
         &self.event_report
     }
+
+    /// Get the events of this step whose payload is of type T, with the id of the stepper that sent them.
+    /// ```ignore
+    /// for (_sender, ShowLogWindow(show)) in token.get_events::<ShowLogWindow>() {
+    ///     self.enabled = *show;
+    /// }
+    /// ```
+    pub fn get_events<T: Any>(&self) -> impl Iterator<Item = (&StepperId, &T)> + '_ {
+        self.event_report.iter().filter_map(|action| match action {
+            StepperAction::Event(stepper_id, payload) => payload.downcast_ref::<T>().map(|value| (stepper_id, value)),
+            _ => None,
+        })
+    }
 }
 
 /// This class contains functions for running the StereoKit library!
@@ -600,14 +717,19 @@ impl MainThreadToken {
 pub struct Sk {
     sk_info: Rc<RefCell<SkInfo>>,
     token: MainThreadToken,
-    #[cfg(feature = "event-loop")]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
     pub(crate) steppers: Steppers,
-    #[cfg(feature = "event-loop")]
+    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
     pub(crate) actions: VecDeque<Box<dyn FnMut()>>,
+    #[cfg(feature = "event-loop")]
+    pub(crate) forward_window_events: bool,
+    #[cfg(feature = "no-event-loop")]
+    #[cfg(not(feature = "event-loop"))]
+    action_receiver: mpsc::Receiver<StepperAction>,
 }
 impl Drop for Sk {
     fn drop(&mut self) {
-        #[cfg(feature = "event-loop")]
+        #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
         self.steppers.shutdown();
         //unsafe { sk_shutdown() }
     }
@@ -653,23 +775,47 @@ impl Sk {
             val
         } {
             true => {
+                #[cfg(feature = "no-event-loop")]
+                #[cfg(not(feature = "event-loop"))]
+                let (action_sender, action_receiver) = mpsc::channel();
                 let sk_info = Rc::new(RefCell::new(SkInfo {
-                    android_app: app,
                     settings: settings.clone(),
                     system_info: unsafe { sk_system_info() },
                     #[cfg(feature = "event-loop")]
                     event_loop_proxy: None,
+                    #[cfg(feature = "no-event-loop")]
+                    #[cfg(not(feature = "event-loop"))]
+                    action_sender,
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    scheduler: Scheduler::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    executor: Executor::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    profiler: Profiler::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    stepper_registry: StepperRegistry::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    asset_cache: AssetCache::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    asset_leaks: AssetLeaks::default(),
+                    vfs: SkInfo::assets_vfs(&app),
+                    android_app: app,
                 }));
                 Ok(Sk {
                     sk_info: sk_info.clone(),
                     token: MainThreadToken {
-                        #[cfg(feature = "event-loop")]
+                        #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
                         event_report: vec![],
                     },
-                    #[cfg(feature = "event-loop")]
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
                     steppers: Steppers::new(sk_info.clone()),
-                    #[cfg(feature = "event-loop")]
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
                     actions: VecDeque::new(),
+                    #[cfg(feature = "event-loop")]
+                    forward_window_events: false,
+                    #[cfg(feature = "no-event-loop")]
+                    #[cfg(not(feature = "event-loop"))]
+                    action_receiver,
                 })
             }
             false => Err(StereoKitError::SkInit(settings.to_string())),
@@ -685,22 +831,46 @@ impl Sk {
             val
         } {
             true => {
+                #[cfg(feature = "no-event-loop")]
+                #[cfg(not(feature = "event-loop"))]
+                let (action_sender, action_receiver) = mpsc::channel();
                 let sk_info = Rc::new(RefCell::new(SkInfo {
                     settings: settings.clone(),
                     system_info: unsafe { sk_system_info() },
                     #[cfg(feature = "event-loop")]
                     event_loop_proxy: None,
+                    #[cfg(feature = "no-event-loop")]
+                    #[cfg(not(feature = "event-loop"))]
+                    action_sender,
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    scheduler: Scheduler::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    executor: Executor::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    profiler: Profiler::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    stepper_registry: StepperRegistry::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    asset_cache: AssetCache::default(),
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+                    asset_leaks: AssetLeaks::default(),
+                    vfs: SkInfo::assets_vfs(settings),
                 }));
                 Ok(Sk {
                     sk_info: sk_info.clone(),
                     token: MainThreadToken {
-                        #[cfg(feature = "event-loop")]
+                        #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
                         event_report: vec![],
                     },
-                    #[cfg(feature = "event-loop")]
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
                     steppers: Steppers::new(sk_info.clone()),
-                    #[cfg(feature = "event-loop")]
+                    #[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
                     actions: VecDeque::new(),
+                    #[cfg(feature = "event-loop")]
+                    forward_window_events: false,
+                    #[cfg(feature = "no-event-loop")]
+                    #[cfg(not(feature = "event-loop"))]
+                    action_receiver,
                 })
             }
             false => Err(StereoKitError::SkInit(settings.to_string())),
@@ -747,6 +917,11 @@ impl Sk {
         self.sk_info.clone()
     }
 
+    /// Get a handle to the virtual file system of the app, with the assets mounted at its root. See [`Vfs`]
+    pub fn get_vfs(&self) -> Vfs {
+        self.sk_info.borrow().get_vfs()
+    }
+
     /// This is a copy of the settings that StereoKit was initialized with, so you can refer back to them a little
     /// easier. These are read only, and keep in mind that some settings are only requests! Check Sk.system and other
     /// properties for the current state of StereoKit.
@@ -879,6 +1054,13 @@ impl Sk {
                     settings: settings.clone(),
                     system_info: unsafe { sk_system_info() },
                     event_loop_proxy: Some(event_loop_proxy),
+                    scheduler: Scheduler::default(),
+                    executor: Executor::default(),
+                    profiler: Profiler::default(),
+                    stepper_registry: StepperRegistry::default(),
+                    asset_cache: AssetCache::default(),
+                    asset_leaks: AssetLeaks::default(),
+                    vfs: SkInfo::assets_vfs(&app),
                     android_app: app,
                 }));
                 Ok((
@@ -887,6 +1069,7 @@ impl Sk {
                         token: MainThreadToken { event_report: vec![] },
                         steppers: Steppers::new(sk_info.clone()),
                         actions: VecDeque::new(),
+                        forward_window_events: false,
                     },
                     event_loop,
                 ))
@@ -922,6 +1105,13 @@ impl Sk {
                     settings: settings.clone(),
                     system_info: unsafe { sk_system_info() },
                     event_loop_proxy: Some(event_loop_proxy),
+                    scheduler: Scheduler::default(),
+                    executor: Executor::default(),
+                    profiler: Profiler::default(),
+                    stepper_registry: StepperRegistry::default(),
+                    asset_cache: AssetCache::default(),
+                    asset_leaks: AssetLeaks::default(),
+                    vfs: SkInfo::assets_vfs(settings),
                 }));
                 Ok((
                     Sk {
@@ -929,6 +1119,7 @@ impl Sk {
                         token: MainThreadToken { event_report: vec![] },
                         steppers: Steppers::new(sk_info.clone()),
                         actions: VecDeque::new(),
+                        forward_window_events: false,
                     },
                     event_loop,
                 ))
@@ -937,50 +1128,21 @@ impl Sk {
         }
     }
 
-    /// This is a non canonical function that let you change all the steppers
-    /// https://stereokit.net/Pages/StereoKit.Framework/IStepper.html
-    pub fn change_steppers(&mut self, steppers: Steppers) {
-        self.steppers = steppers;
-    }
-
-    /// This will queue up some code to be run on StereoKit’s main thread! Immediately after StereoKit’s Step, all
-    /// callbacks registered here will execute, and then removed from the list.
-    /// <https://stereokit.net/Pages/StereoKit/SK/ExecuteOnMain.html>
-    pub fn execute_on_main<F: FnMut() + 'static>(&mut self, action: F) {
-        self.actions.push_back(Box::new(action))
-    }
-
-    /// convenient way to push some Add steppers action
-    pub fn push_action(&mut self, action: StepperAction) {
-        self.steppers.push_action(action);
-    }
-
     /// Get an event_loop_proxy clone to send events
     pub fn get_event_loop_proxy(&self) -> Option<EventLoopProxy<StepperAction>> {
         let sk = self.sk_info.as_ref();
         sk.borrow().get_event_loop_proxy()
     }
 
-    /// Steps all StereoKit systems, and inserts user code via callback between the appropriate system updates.
-    /// <https://stereokit.net/Pages/StereoKit/SK/Step.html>
-    ///
-    /// see also [`crate::sk::sk_step`]
-    #[deprecated(since = "0.40.0", note = "see SkClosure::about_to_wait() instead")]
-    pub fn step_looped<F: FnMut(&mut Sk)>(&mut self, on_step: &mut F) -> bool {
-        if unsafe { sk_step(None) } == 0 {
-            return false;
-        }
-        if !self.steppers.step(&mut self.token) {
-            self.quit(None)
-        };
-
-        while let Some(mut action) = self.actions.pop_front() {
-            action();
-        }
-
-        on_step(self);
-
-        true
+    /// Non canonical function. If true, the winit window events that StereoKit doesn't consume (focus, mouse wheel)
+    /// are sent to the steppers as [`winit::event::WindowEvent`] events. Default is false.
+    /// ```ignore
+    /// for (_, event) in token.get_events::<WindowEvent>() {
+    ///     if let WindowEvent::MouseWheel { delta, .. } = event { /* ... */ }
+    /// }
+    /// ```
+    pub fn forward_window_events(&mut self, forward: bool) {
+        self.forward_window_events = forward;
     }
 
     /// A way to execute without event_loop frame. This can be use only for PC programs
@@ -1042,8 +1204,16 @@ impl Sk {
                     Log::diag(format!("UserEvent {:?}", action));
                     self.push_action(action);
                 }
-                Event::Suspended => Log::info("Suspended !!"),
-                Event::Resumed => Log::info("Resumed !!"),
+                Event::Suspended => {
+                    Log::info("Suspended !!");
+                    self.steppers.suspend();
+                    self.push_action(StepperAction::Suspended);
+                }
+                Event::Resumed => {
+                    Log::info("Resumed !!");
+                    self.steppers.resume();
+                    self.push_action(StepperAction::Resumed);
+                }
                 Event::AboutToWait => {
                     if !&self.step_looped(&mut on_step) {
                         elwt.exit()
@@ -1060,3 +1230,231 @@ impl Sk {
             });
     }
 }
+
+#[cfg(any(feature = "event-loop", feature = "no-event-loop"))]
+impl Sk {
+    /// This is a non canonical function that let you change all the steppers
+    /// https://stereokit.net/Pages/StereoKit.Framework/IStepper.html
+    pub fn change_steppers(&mut self, steppers: Steppers) {
+        self.steppers = steppers;
+    }
+
+    /// This will queue up some code to be run on StereoKit’s main thread! Immediately after StereoKit’s Step, all
+    /// callbacks registered here will execute, and then removed from the list.
+    /// <https://stereokit.net/Pages/StereoKit/SK/ExecuteOnMain.html>
+    pub fn execute_on_main<F: FnMut() + 'static>(&mut self, action: F) {
+        self.actions.push_back(Box::new(action))
+    }
+
+    /// Non canonical function to get the stepper identified by stepper_id if it is of type T.
+    /// Steppers added during this frame are available after the next step.
+    pub fn get_stepper<T: IStepper>(&mut self, stepper_id: impl AsRef<str>) -> Option<&mut T> {
+        self.steppers.get_stepper::<T>(stepper_id)
+    }
+
+    /// Non canonical function to get all the steppers of type T with their ids.
+    /// Steppers added during this frame are available after the next step.
+    pub fn get_steppers_of<T: IStepper>(&mut self) -> impl Iterator<Item = (&StepperId, &mut T)> {
+        self.steppers.get_steppers_of::<T>()
+    }
+
+    /// Non canonical function. If true, a stepper that panics is removed and reported instead of tearing down the
+    /// whole app. See [`Steppers::catch_panics`]
+    pub fn catch_stepper_panics(&mut self, catch_panics: bool) {
+        self.steppers.catch_panics(catch_panics);
+    }
+
+    /// Non canonical function to list the id, type and enabled state of all the registered steppers.
+    pub fn get_stepper_infos(&self) -> Vec<StepperInfo> {
+        self.steppers.get_stepper_infos()
+    }
+
+    /// convenient way to push some Add steppers action
+    pub fn push_action(&mut self, action: StepperAction) {
+        self.steppers.push_action(action);
+    }
+
+    /// Register a stepper created on the main thread. Unlike StepperAction::add the stepper doesn't have to be Send,
+    /// so it can hold Rc, Tex, Mesh... Use StepperAction::add with the EventLoopProxy to add a stepper from another
+    /// thread.
+    pub fn add_stepper<T: IStepper>(&mut self, stepper_id: impl AsRef<str>, stepper: T) {
+        self.steppers.add_local(stepper_id, stepper);
+    }
+
+    /// Register a stepper of type T created with its default value on the main thread. See [`Sk::add_stepper`]
+    pub fn add_stepper_default<T: IStepper + Default>(&mut self, stepper_id: impl AsRef<str>) {
+        self.steppers.add_local_default::<T>(stepper_id);
+    }
+
+    /// Register a stepper that doesn't belong to any scene, so it survives the scene changes. See [`Scene`]
+    pub fn add_global_stepper<T: IStepper>(&mut self, stepper_id: impl AsRef<str>, stepper: T) {
+        self.steppers.add_global(stepper_id, stepper);
+    }
+
+    /// Push a scene on top of the scene stack. See [`Scene`]
+    pub fn push_scene(&mut self, scene: Scene) {
+        self.steppers.push_scene(scene);
+    }
+
+    /// Remove the top scene and its steppers. See [`Scene`]
+    pub fn pop_scene(&mut self) {
+        self.steppers.pop_scene();
+    }
+
+    /// Replace the top scene and its steppers with this one. See [`Scene`]
+    pub fn replace_scene(&mut self, scene: Scene) {
+        self.steppers.replace_scene(scene);
+    }
+
+    /// Pause or resume the steppers of a scene. See [`Scene`]
+    pub fn pause_scene(&mut self, name: impl AsRef<str>, paused: bool) {
+        self.steppers.pause_scene(name, paused);
+    }
+
+    /// Keep the states of the steppers between two sessions. See [`Steppers::persist_state`]
+    pub fn persist_stepper_state(&mut self, value: bool) {
+        self.steppers.persist_state(value);
+    }
+
+    /// The names of the scenes of the stack, the top one last.
+    pub fn get_scenes(&self) -> Vec<String> {
+        self.steppers.get_scenes()
+    }
+
+    /// Get a handle to the main thread scheduler to run some code or push some StepperActions later.
+    /// See [`Scheduler`]
+    pub fn get_scheduler(&self) -> Scheduler {
+        let sk = self.sk_info.as_ref();
+        sk.borrow().get_scheduler()
+    }
+
+    /// Get a handle to the main thread executor. See [`Executor`]
+    pub fn get_executor(&self) -> Executor {
+        let sk = self.sk_info.as_ref();
+        sk.borrow().get_executor()
+    }
+
+    /// Get a handle to the profiler measuring the time spent by each stepper and by the app's on_step closure.
+    /// See [`Profiler`]
+    pub fn get_profiler(&self) -> Profiler {
+        let sk = self.sk_info.as_ref();
+        sk.borrow().get_profiler()
+    }
+
+    /// Get a handle to the named stepper factories used by StepperAction::add_by_name and the scene files.
+    /// See [`StepperRegistry`]
+    pub fn get_stepper_registry(&self) -> StepperRegistry {
+        let sk = self.sk_info.as_ref();
+        sk.borrow().get_stepper_registry()
+    }
+
+    /// Get a handle to the cache sharing the assets loaded from files between the steppers. See [`AssetCache`]
+    pub fn get_asset_cache(&self) -> AssetCache {
+        let sk = self.sk_info.as_ref();
+        sk.borrow().get_asset_cache()
+    }
+
+    /// Get a handle to the detection of the assets created and never released. See [`AssetLeaks`]
+    pub fn get_asset_leaks(&self) -> AssetLeaks {
+        let sk = self.sk_info.as_ref();
+        sk.borrow().get_asset_leaks()
+    }
+
+    /// Spawn a future polled once per frame on the main thread, after the scheduler tasks. See [`Executor`] and
+    /// [`crate::event_loop::Wait`] for the futures to await.
+    pub fn spawn_local<F: Future<Output = ()> + 'static>(&self, future: F) -> TaskHandle {
+        self.get_executor().spawn_local(future)
+    }
+
+    /// Steps all StereoKit systems, and inserts user code via callback between the appropriate system updates.
+    /// <https://stereokit.net/Pages/StereoKit/SK/Step.html>
+    ///
+    /// Without winit (feature no-event-loop), this is the step to call from your own loop: it also reads the actions of
+    /// SkInfo::get_action_sender.
+    ///
+    /// see also [`crate::sk::sk_step`]
+    #[cfg_attr(
+        feature = "event-loop",
+        deprecated(since = "0.40.0", note = "see SkClosure::about_to_wait() instead")
+    )]
+    pub fn step_looped<F: FnMut(&mut Sk)>(&mut self, on_step: &mut F) -> bool {
+        #[cfg(feature = "no-event-loop")]
+        #[cfg(not(feature = "event-loop"))]
+        #[cfg(target_os = "android")]
+        self.poll_android_events();
+
+        if unsafe { sk_step(None) } == 0 {
+            return false;
+        }
+
+        #[cfg(feature = "no-event-loop")]
+        #[cfg(not(feature = "event-loop"))]
+        while let Ok(action) = self.action_receiver.try_recv() {
+            match action {
+                StepperAction::Run(job) => job(self),
+                _ => self.push_action(action),
+            }
+        }
+
+        if !self.steppers.step(&mut self.token) {
+            self.quit(None)
+        };
+
+        while let Some(mut action) = self.actions.pop_front() {
+            action();
+        }
+
+        self.get_scheduler().step(self);
+        self.get_executor().step();
+
+        let profiler = self.get_profiler();
+        profiler.measure(Profiler::APP_STEP_ID, || on_step(self));
+
+        true
+    }
+}
+
+#[cfg(feature = "no-event-loop")]
+#[cfg(not(feature = "event-loop"))]
+impl Sk {
+    /// This passes application execution over to StereoKit without winit. This continuously steps all StereoKit
+    /// systems and the steppers, and inserts user code via callback after them. Once SK.Quit is called, or a stepper
+    /// pushes StepperAction::Quit, it calls the shutdown callback, shuts down the steppers then StereoKit.
+    /// Use it for the apps that don't need winit or for AppMode::Offscreen runs. Call Sk::step_looped from your own
+    /// loop if StereoKit is embedded in another main loop.
+    /// <https://stereokit.net/Pages/StereoKit/SK/Run.html>
+    /// ```ignore
+    /// let mut sk = SkSettings::default().app_name("offscreen").mode(AppMode::Offscreen).init()?;
+    /// sk.add_stepper_default::<FlyOver>("FlyOver");
+    /// sk.run(|sk| { /* on_step */ }, |sk| Log::info(format!("QuitReason is {:?}", sk.get_quit_reason())));
+    /// ```
+    pub fn run<U: FnMut(&mut Sk), S: FnMut(&mut Sk)>(mut self, mut on_step: U, mut on_shutdown: S) {
+        while self.step_looped(&mut on_step) {}
+        on_shutdown(&mut self);
+        // the steppers are shut down when Sk is dropped
+        drop(self);
+        Sk::shutdown();
+    }
+
+    /// Read the android-activity events without blocking so the steppers are suspended/resumed like they are with
+    /// winit.
+    #[cfg(target_os = "android")]
+    fn poll_android_events(&mut self) {
+        use android_activity::{MainEvent, PollEvent};
+        let app = self.sk_info.borrow_mut().get_android_app().clone();
+        app.poll_events(Some(std::time::Duration::ZERO), |event| match event {
+            PollEvent::Main(MainEvent::Pause) => {
+                Log::info("Suspended !!");
+                self.steppers.suspend();
+                self.push_action(StepperAction::Suspended);
+            }
+            PollEvent::Main(MainEvent::Resume { .. }) => {
+                Log::info("Resumed !!");
+                self.steppers.resume();
+                self.push_action(StepperAction::Resumed);
+            }
+            PollEvent::Main(MainEvent::Destroy) => self.quit(None),
+            _ => {}
+        });
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     maths::{Bool32T, Vec3},
-    system::IAsset,
+    system::{Asset, AssetLoad, AssetType, IAsset},
+    vfs::Vfs,
     StereoKitError,
 };
 
@@ -67,13 +68,26 @@ extern "C" {
 }
 
 impl IAsset for Sound {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Sound;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Sound::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Sound(sound) => Some(sound),
+            _ => None,
+        }
+    }
 }
 
 impl Sound {
@@ -103,6 +117,25 @@ impl Sound {
         ))
     }
 
+    /// Non canonical function. Same as from_file, returned as an [`AssetLoad`] so sounds can be waited for like the
+    /// other assets. The sound is loaded synchronously.
+    pub fn from_file_async(file_utf8: impl AsRef<Path>) -> AssetLoad<Sound> {
+        AssetLoad::new(Self::from_file(&file_utf8), file_utf8)
+    }
+
+    /// Non canonical function. Loads a sound from a [`Vfs`] (directories, zip archives or bundles). The sounds of the
+    /// archives and bundles are extracted first, see [`Vfs::get_file`]. The asset id is the virtual path (see
+    /// [`Vfs::virtual_path`]), the sound already loaded with this id is returned if any.
+    pub fn from_vfs(vfs: &Vfs, path: impl AsRef<Path>) -> Result<Sound, StereoKitError> {
+        let id = Vfs::virtual_path(path);
+        if let Ok(sound) = Self::find(&id) {
+            return Ok(sound);
+        }
+        let mut sound = Self::from_file(vfs.get_file(&id)?)?;
+        sound.id(&id);
+        Ok(sound)
+    }
+
     /// This function will create a sound from an array of samples. Values should range from -1 to +1, and there should
     /// be 48,000 values per second of audio.
     /// <https://stereokit.net/Pages/StereoKit/Sound/FromSamples.html>
@@ -1,7 +1,7 @@
 use crate::{
     maths::{Matrix, Vec2},
     sk::MainThreadToken,
-    system::{IAsset, TextAlign},
+    system::{Asset, AssetType, IAsset, TextAlign},
     tex::{Tex, TexT},
     util::Color32,
     StereoKitError,
@@ -76,13 +76,26 @@ extern "C" {
 }
 
 impl IAsset for Sprite {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Sprite;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Sprite::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Sprite(sprite) => Some(sprite),
+            _ => None,
+        }
+    }
 }
 
 impl Sprite {
@@ -1,9 +1,13 @@
 use std::{
     ffi::{c_char, c_ushort, c_void, CStr, CString},
     fmt,
+    future::Future,
     mem::{size_of, transmute_copy},
-    path::Path,
+    path::{Path, PathBuf},
+    pin::Pin,
     ptr::{null, null_mut, NonNull},
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
 };
 
 use crate::{
@@ -18,7 +22,7 @@ use crate::{
     sk::{MainThreadToken, OriginMode},
     sound::{Sound, SoundT, _SoundT},
     sprite::{Sprite, _SpriteT},
-    tex::{Tex, TexFormat, TexT, _TexT},
+    tex::{Tex, TexFormat, TexOnLoad, TexT, _TexT},
     util::{Color128, Color32, SphericalHarmonics},
     StereoKitError,
 };
@@ -26,16 +30,37 @@ use crate::{
 /// All StereoKit assets implement this interface! This is mostly to help group and hold Asset objects, and is
 /// particularly useful when working with Assets at a high level with the Assets class.
 /// <https://stereokit.net/Pages/StereoKit/IAsset.html>
-pub trait IAsset {
+pub trait IAsset: Sized {
+    /// Non canonical constant. The AssetType of this kind of asset in the asset list of StereoKit.
+    const ASSET_TYPE: AssetType;
+
     /// sets the unique identifier of this asset resource! This can be helpful for debugging, managine your assets, or
     /// finding them later on!
     /// <https://stereokit.net/Pages/StereoKit/IAsset/Id.html>
-    //fn id(&mut self, id: impl AsRef<str>);
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self;
 
     /// gets the unique identifier of this asset resource! This can be helpful for debugging, managine your assets, or
     /// finding them later on!
     /// <https://stereokit.net/Pages/StereoKit/IAsset/Id.html>
     fn get_id(&self) -> &str;
+
+    /// Non canonical function. The loading state of this asset. Only textures are loaded in the background, the other
+    /// assets are Loaded as soon as they are created.
+    fn get_asset_state(&self) -> AssetState {
+        AssetState::Loaded
+    }
+
+    /// Searches the asset list for an asset of this type with the given id. The default implementation looks at all
+    /// the assets of this type, the assets with a native find use it instead.
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        let id = id.as_ref();
+        Assets::iter::<Self>()
+            .find(|asset| asset.get_id() == id)
+            .ok_or_else(|| StereoKitError::AssetNotFound(id.to_string(), PathBuf::new()))
+    }
+
+    /// Non canonical function. The typed asset if this Asset is of this type.
+    fn from_asset(asset: Asset) -> Option<Self>;
 }
 
 /// StereoKit uses an asynchronous loading system to prevent assets from blocking execution! This means that asset
@@ -65,11 +90,130 @@ pub enum AssetState {
     Loaded = 3,
 }
 
+impl AssetState {
+    /// Non canonical function. true if the asset is Loaded or failed to load, StereoKit won't change its state
+    /// anymore.
+    pub fn is_finished(&self) -> bool {
+        *self == AssetState::Loaded || (*self as i32) < 0
+    }
+
+    /// Non canonical function. Convert a finished state to a result: Ok for Loaded, the matching error for the
+    /// failed states. None while the asset is loading.
+    /// * id - the id of the asset, for the error.
+    /// * path - the file of the asset, for the error.
+    pub fn to_result(self, id: impl AsRef<str>, path: impl AsRef<Path>) -> Option<Result<(), StereoKitError>> {
+        let (id, path) = (id.as_ref().to_string(), path.as_ref().to_path_buf());
+        match self {
+            AssetState::Loaded => Some(Ok(())),
+            AssetState::NotFound => Some(Err(StereoKitError::AssetNotFound(id, path))),
+            AssetState::Unsupported => Some(Err(StereoKitError::AssetUnsupported(id, path))),
+            AssetState::Error => Some(Err(StereoKitError::AssetLoad(id, path))),
+            _ => None,
+        }
+    }
+}
+
+/// Non canonical structure to wait for the end of the loading of an asset created from a file. Await it in an async
+/// block (see event_loop::Executor), poll it with try_take once per frame in your own loop, or use
+/// Executor::on_loaded to get a callback.
+/// It is ready with the asset once it is Loaded, or with an AssetNotFound, AssetUnsupported or AssetLoad error.
+///
+/// The textures of Tex::from_file_async wake the awaiting task from a [`Tex::on_load`] callback, so they can be awaited
+/// with any executor. The other assets don't have a load callback and must be polled again until they are ready, as
+/// Executor does once per frame.
+/// ```ignore
+/// executor.spawn_local(async {
+///     match Tex::from_file_async("textures/parquet2/parquet2.ktx2", true, None).await {
+///         Ok(tex) => material.diffuse_tex(tex),
+///         Err(error) => Log::warn(format!("no parquet: {}", error)),
+///     };
+/// });
+/// ```
+pub struct AssetLoad<T: IAsset> {
+    asset: Option<Result<T, StereoKitError>>,
+    path: PathBuf,
+    waker: Arc<Mutex<Option<Waker>>>,
+    on_load: Option<TexOnLoad>,
+}
+
+impl<T: IAsset> AssetLoad<T> {
+    /// Wait for the asset returned by a from_file function.
+    /// * created - the result of the creation of the asset. An error is given back as is.
+    /// * path - the file of the asset, for the errors.
+    pub fn new(created: Result<T, StereoKitError>, path: impl AsRef<Path>) -> Self {
+        Self { asset: Some(created), path: path.as_ref().to_path_buf(), waker: Arc::default(), on_load: None }
+    }
+
+    /// The asset being loaded, None if it couldn't be created or has already been taken.
+    pub fn get_asset(&self) -> Option<&T> {
+        self.asset.as_ref().and_then(|created| created.as_ref().ok())
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The current state of the asset. Error if it couldn't be created, None if it has already been taken.
+    pub fn get_state(&self) -> AssetState {
+        match &self.asset {
+            Some(Ok(asset)) => asset.get_asset_state(),
+            Some(Err(_)) => AssetState::Error,
+            None => AssetState::None,
+        }
+    }
+
+    /// The asset or the loading error once the loading is finished, None while loading or if it has already been
+    /// taken.
+    pub fn try_take(&mut self) -> Option<Result<T, StereoKitError>> {
+        let result = match self.asset.as_ref()? {
+            Ok(asset) => asset.get_asset_state().to_result(asset.get_id(), &self.path)?,
+            Err(_) => Ok(()),
+        };
+        let created = self.asset.take()?;
+        self.on_load = None;
+        Some(result.and(created))
+    }
+}
+
+impl AssetLoad<Tex> {
+    /// Wake the task awaiting this texture from a [`Tex::on_load`] callback. The callback is removed once the texture
+    /// is taken or when this is dropped.
+    pub fn wake_on_load(mut self) -> Self {
+        if let Some(Ok(tex)) = &self.asset {
+            let waker = self.waker.clone();
+            self.on_load = Some(tex.on_load(move || {
+                if let Some(waker) = waker.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take() {
+                    waker.wake()
+                }
+            }));
+        }
+        self
+    }
+}
+
+// the asset is never pinned
+impl<T: IAsset> Unpin for AssetLoad<T> {}
+
+/// Textures set with AssetLoad::wake_on_load wake the task when they are loaded, the other assets must be polled until
+/// they are ready (Executor does it once per frame).
+impl<T: IAsset> Future for AssetLoad<T> {
+    type Output = Result<T, StereoKitError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // the waker is stored before checking the state, so a load ending in between still wakes the task
+        *self.waker.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(cx.waker().clone());
+        match self.try_take() {
+            Some(result) => Poll::Ready(result),
+            None => Poll::Pending,
+        }
+    }
+}
+
 /// A flag for what ‘type’ an Asset may store.
 ///
 /// None -> No type, this may come from some kind of invalid Asset id.
 /// <https://stereokit.net/Pages/StereoKit/AssetType.html>
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(u32)]
 pub enum AssetType {
     None = 0,
@@ -144,6 +288,52 @@ impl fmt::Display for Asset {
     }
 }
 
+impl Asset {
+    /// Non canonical function. The AssetType of this asset, AssetType::None for Asset::None.
+    pub fn get_type(&self) -> AssetType {
+        match self {
+            Asset::None => AssetType::None,
+            Asset::Mesh(_) => AssetType::Mesh,
+            Asset::Tex(_) => AssetType::Tex,
+            Asset::Shader(_) => AssetType::Shader,
+            Asset::Material(_) => AssetType::Material,
+            Asset::Model(_) => AssetType::Model,
+            Asset::Font(_) => AssetType::Font,
+            Asset::Sprite(_) => AssetType::Sprite,
+            Asset::Sound(_) => AssetType::Sound,
+            Asset::Solid(_) => AssetType::Solid,
+            Asset::Anchor(_) => AssetType::Anchor,
+            Asset::RenderList(_) => AssetType::RenderList,
+        }
+    }
+
+    /// Non canonical function. The id of this asset, "" for Asset::None and Asset::Solid.
+    pub fn get_id(&self) -> &str {
+        match self {
+            Asset::None | Asset::Solid(_) => "",
+            Asset::Mesh(v) => v.get_id(),
+            Asset::Tex(v) => v.get_id(),
+            Asset::Shader(v) => v.get_id(),
+            Asset::Material(v) => v.get_id(),
+            Asset::Model(v) => v.get_id(),
+            Asset::Font(v) => v.get_id(),
+            Asset::Sprite(v) => v.get_id(),
+            Asset::Sound(v) => v.get_id(),
+            Asset::Anchor(v) => v.get_id(),
+            Asset::RenderList(v) => v.get_id(),
+        }
+    }
+
+    /// Non canonical function. The loading state of this asset, see IAsset::get_asset_state.
+    pub fn get_state(&self) -> AssetState {
+        match self {
+            Asset::None | Asset::Solid(_) => AssetState::None,
+            Asset::Tex(v) => v.get_asset_state(),
+            _ => AssetState::Loaded,
+        }
+    }
+}
+
 /// Iterator on assets
 ///
 /// see also [Assets::all][Assets::type]
@@ -189,8 +379,11 @@ impl Iterator for AssetIter {
 }
 
 impl AssetIter {
-    /// Get the asset
+    /// Get the asset. The asset list doesn't give a reference to the asset, so we add the one the handle releases.
     fn to_asset(self, asset_type: AssetType, c_id: *mut c_void) -> Asset {
+        if !matches!(asset_type, AssetType::None | AssetType::Solid) {
+            unsafe { asset_addref(c_id) };
+        }
         match asset_type {
             AssetType::None => Asset::None,
             AssetType::Mesh => Asset::Mesh(Mesh(NonNull::new(c_id as *mut _MeshT).unwrap())),
@@ -230,18 +423,38 @@ impl Assets {
     /// supported sound format by asset Sound <https://stereokit.net/Pages/StereoKit/Sound.html>
     pub const SOUND_FORMATS: [&'static str; 2] = [".wav", ".mp3"];
 
-    /// This is an iterator upon all assets loaded by StereoKit at the current moment.
+    /// This is an iterator upon all assets loaded by StereoKit at the current moment. Each asset returned holds its own
+    /// reference, released when it is dropped, so the assets stay alive while they are kept.
     /// <https://stereokit.net/Pages/StereoKit/Assets/All.html>
     pub fn all() -> AssetIter {
         AssetIter::iterate(None)
     }
 
-    /// This is an iterator upon all assets matching the specified type.
+    /// This is an iterator upon all assets matching the specified type. As with [`Assets::all`], each asset returned
+    /// holds its own reference.
     /// <https://stereokit.net/Pages/StereoKit/Assets/Type.html>
     pub fn all_of_type(asset_type: AssetType) -> AssetIter {
         AssetIter::iterate(Some(asset_type))
     }
 
+    /// Non canonical function. This is an iterator upon all the assets of type T.
+    /// ```ignore
+    /// for tex in Assets::iter::<Tex>() {
+    ///     Log::diag(format!("{} : {:?}", tex.get_id(), tex.get_asset_state()));
+    /// }
+    /// ```
+    pub fn iter<T: IAsset>() -> impl Iterator<Item = T> {
+        AssetIter::iterate(Some(T::ASSET_TYPE)).filter_map(T::from_asset)
+    }
+
+    /// Non canonical function. Searches the asset list for an asset of type T with the given id.
+    /// ```ignore
+    /// let floor = Assets::find::<Material>("floor")?;
+    /// ```
+    pub fn find<T: IAsset>(id: impl AsRef<str>) -> Result<T, StereoKitError> {
+        T::find(id)
+    }
+
     /// This is the index of the current asset loading task. Note that to load one asset, multiple tasks are generated.
     /// <https://stereokit.net/Pages/StereoKit/Assets/CurrentTask.html>
     ///
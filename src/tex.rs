@@ -3,15 +3,20 @@ use std::{
     mem::size_of,
     path::{Path, PathBuf},
     ptr::{null_mut, NonNull},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
 };
 
 use crate::{
     maths::{Bool32T, Vec3},
     system::{
-        render_enable_skytex, render_get_skylight, render_get_skytex, render_set_skylight, render_set_skytex,
-        AssetState, IAsset, Log,
+        render_enable_skytex, render_get_skylight, render_get_skytex, render_set_skylight, render_set_skytex, Asset,
+        AssetLoad, AssetState, AssetType, IAsset, Log,
     },
     util::{Color128, Color32, Gradient, GradientKey, GradientT, SphericalHarmonics},
+    vfs::Vfs,
     StereoKitError,
 };
 
@@ -186,6 +191,44 @@ impl Drop for Tex {
         unsafe { tex_release(self.0.as_ptr()) };
     }
 }
+
+/// A callback registered with [`Tex::on_load`]. It keeps the texture alive and is removed when dropped.
+#[derive(Debug)]
+pub struct TexOnLoad {
+    tex: Tex,
+    id: u64,
+}
+
+impl Drop for TexOnLoad {
+    fn drop(&mut self) {
+        let texture = self.tex.0.as_ptr() as usize;
+        let mut callbacks = TEX_ON_LOAD.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        callbacks.retain(|callback| callback.id != self.id);
+        if !callbacks.iter().any(|callback| callback.texture == texture) {
+            unsafe { tex_on_load_remove(self.tex.0.as_ptr(), Some(tex_on_load_trampoline)) }
+        }
+    }
+}
+
+struct TexOnLoadCallback {
+    texture: usize,
+    id: u64,
+    on_load: Box<dyn FnMut() + Send>,
+}
+
+/// The callbacks of Tex::on_load by texture. StereoKit removes its callbacks by function only, so the trampoline is
+/// registered once per texture and calls all the callbacks of the texture.
+static TEX_ON_LOAD: Mutex<Vec<TexOnLoadCallback>> = Mutex::new(Vec::new());
+static NEXT_ON_LOAD_ID: AtomicU64 = AtomicU64::new(0);
+
+unsafe extern "C" fn tex_on_load_trampoline(texture: TexT, _context: *mut c_void) {
+    let texture = texture as usize;
+    let mut callbacks = TEX_ON_LOAD.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    for callback in callbacks.iter_mut().filter(|callback| callback.texture == texture) {
+        (callback.on_load)()
+    }
+}
+
 impl AsRef<Tex> for Tex {
     fn as_ref(&self) -> &Tex {
         self
@@ -312,13 +355,30 @@ extern "C" {
 }
 
 impl IAsset for Tex {
-    // fn id(&mut self, id: impl AsRef<str>) {
-    //     self.id(id);
-    // }
+    const ASSET_TYPE: AssetType = AssetType::Tex;
+
+    fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        self.id(id)
+    }
 
     fn get_id(&self) -> &str {
         self.get_id()
     }
+
+    fn find(id: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        Tex::find(id)
+    }
+
+    fn from_asset(asset: Asset) -> Option<Self> {
+        match asset {
+            Asset::Tex(tex) => Some(tex),
+            _ => None,
+        }
+    }
+
+    fn get_asset_state(&self) -> AssetState {
+        self.get_asset_state()
+    }
 }
 
 /// A Default texture is asked when a Tex creation or find returned an error.
@@ -378,6 +438,33 @@ impl Tex {
             .ok_or(StereoKitError::TexFile(path_buf, "tex_create failed".to_string()))?))
     }
 
+    /// Non canonical function. Same as from_file but the result is ready only when the texture is loaded or failed to
+    /// load, with an AssetNotFound or AssetUnsupported error instead of a fallback texture. The awaiting task is woken
+    /// by a [`Tex::on_load`] callback. See [`AssetLoad`]
+    /// * priority - If None will be set to 10
+    pub fn from_file_async(file_utf8: impl AsRef<Path>, srgb_data: bool, priority: Option<i32>) -> AssetLoad<Tex> {
+        AssetLoad::new(Self::from_file(&file_utf8, srgb_data, priority), file_utf8).wake_on_load()
+    }
+
+    /// Non canonical function. Loads an image file from a [`Vfs`] (directories, zip archives or bundles). The asset id
+    /// is the virtual path (see [`Vfs::virtual_path`]), the texture already loaded with this id is returned if any.
+    /// * priority - If None will be set to 10
+    pub fn from_vfs(
+        vfs: &Vfs,
+        path: impl AsRef<Path>,
+        srgb_data: bool,
+        priority: Option<i32>,
+    ) -> Result<Tex, StereoKitError> {
+        let id = Vfs::virtual_path(path);
+        if let Ok(tex) = Self::find(&id) {
+            return Ok(tex);
+        }
+        let data = vfs.read(&id)?;
+        let mut tex = Self::from_memory(&data, srgb_data, priority)?;
+        tex.id(&id);
+        Ok(tex)
+    }
+
     /// Loads an array of image files directly into a single array texture! Array textures are often useful for shader
     /// effects, layering, material merging, weird stuff, and will generally need a specific shader to support it.
     /// Supported formats are: jpg, png, tga, bmp, psd, gif, hdr, pic, ktx2. Asset Id will be the hash of all the
@@ -934,6 +1021,35 @@ impl Tex {
         unsafe { tex_asset_state(self.0.as_ptr()) }
     }
 
+    /// Non canonical function. Register a callback StereoKit calls when the texture has finished loading, at once if
+    /// it is already loaded, and again if it is reloaded. StereoKit may call it from its loading thread, so keep it
+    /// short. It must not register or drop another load callback. The callback is removed when the returned
+    /// [`TexOnLoad`] is dropped. For most uses, [`Tex::from_file_async`] is easier.
+    /// <https://stereokit.net/Pages/StereoKit/Tex/OnLoaded.html>
+    ///
+    /// see also [`crate::tex::tex_on_load`]
+    pub fn on_load(&self, on_load: impl FnMut() + Send + 'static) -> TexOnLoad {
+        let id = NEXT_ON_LOAD_ID.fetch_add(1, Ordering::Relaxed);
+        let texture = self.0.as_ptr() as usize;
+        let first = {
+            let mut callbacks = TEX_ON_LOAD.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let first = !callbacks.iter().any(|callback| callback.texture == texture);
+            callbacks.push(TexOnLoadCallback { texture, id, on_load: Box::new(on_load) });
+            first
+        };
+        if first {
+            // StereoKit calls the trampoline at once if the texture is already loaded, so the lock must be released
+            unsafe { tex_on_load(self.0.as_ptr(), Some(tex_on_load_trampoline), null_mut()) }
+        } else if self.get_asset_state().is_finished() {
+            let mut callbacks = TEX_ON_LOAD.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if let Some(callback) = callbacks.iter_mut().find(|callback| callback.id == id) {
+                (callback.on_load)()
+            }
+        }
+        unsafe { tex_addref(self.0.as_ptr()) };
+        TexOnLoad { tex: Tex(self.0), id }
+    }
+
     /// The StereoKit format this texture was initialized with. This will be a blocking call if AssetState is less than
     /// LoadedMeta so None will be return instead
     /// <https://stereokit.net/Pages/StereoKit/Tex/Format.html>
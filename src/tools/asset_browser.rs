@@ -0,0 +1,490 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    event_loop::{IStepper, StepperId, StepperOrder, StepperParams, StepperPhase, StringEvent},
+    material::{Cull, Material},
+    maths::{units::CM, Bounds, Matrix, Pose, Quat, Vec2, Vec3},
+    mesh::Mesh,
+    model::Model,
+    sk::{MainThreadToken, SkInfo},
+    sprite::Sprite,
+    system::{Asset, AssetState, AssetType, Assets, Log, RenderClear, RenderLayer, Renderer},
+    tex::{Tex, TexFormat},
+    ui::{Ui, UiBtnLayout},
+    util::Time,
+    StereoKitError,
+};
+
+/// The string event key to show/hide the asset browser, prefer the typed event [`ShowAssetBrowser`].
+pub const SHOW_ASSET_BROWSER: &str = "ShowAssetBrowser";
+
+/// The typed event to show/hide the asset browser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowAssetBrowser(pub bool);
+
+/// The asset types listed by the browser, in this order.
+const ASSET_TYPES: [AssetType; 10] = [
+    AssetType::Tex,
+    AssetType::Mesh,
+    AssetType::Material,
+    AssetType::Model,
+    AssetType::Shader,
+    AssetType::Font,
+    AssetType::Sprite,
+    AssetType::Sound,
+    AssetType::Anchor,
+    AssetType::RenderList,
+];
+
+/// Where the previewed asset is drawn, far from the scene (and beyond the far clip of the main camera). The thumbnails
+/// are drawn next to it, THUMBNAIL_SPACING apart so each camera only sees its own asset.
+const PREVIEW_AT: Vec3 = Vec3 { x: 0.0, y: -1000.0, z: 0.0 };
+
+/// The size of the preview texture in pixels.
+const PREVIEW_SIZE: usize = 256;
+
+/// The size of the thumbnail textures of the list in pixels.
+const THUMBNAIL_SIZE: usize = 64;
+
+/// The distance between the assets drawn for the previews, in meters.
+const THUMBNAIL_SPACING: f32 = 10.0;
+
+/// The ids of the textures and materials of the previews start with this.
+const PREVIEW_ID: &str = "AssetBrowserPreview";
+
+/// A description of a live asset, see [`AssetInfo::collect`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetInfo {
+    pub asset_type: AssetType,
+    pub id: String,
+    pub state: AssetState,
+    /// Dimensions and format of a Tex, vertex and index counts of a Mesh, node and animation counts of a Model...
+    pub details: String,
+}
+
+impl AssetInfo {
+    pub fn new(asset: &Asset) -> Self {
+        let details = match asset {
+            Asset::Tex(tex) => match (tex.get_width(), tex.get_height()) {
+                (Some(width), Some(height)) => {
+                    format!("{}x{} {:?}", width, height, tex.get_format().unwrap_or(TexFormat::None))
+                }
+                _ => "loading".into(),
+            },
+            Asset::Mesh(mesh) => format!("{} verts {} inds", mesh.get_vert_count(), mesh.get_ind_count()),
+            Asset::Model(model) => {
+                format!("{} nodes {} anims", model.get_nodes().get_count(), model.get_anims().get_count())
+            }
+            Asset::Material(material) => format!("shader {}", material.get_shader().get_id()),
+            Asset::Sprite(sprite) => format!("{}x{}", sprite.get_width(), sprite.get_height()),
+            Asset::Sound(sound) => format!("{:.2} s", sound.get_duration()),
+            Asset::RenderList(render_list) => {
+                format!("{} items ({} last frame)", render_list.get_count(), render_list.get_prev_count())
+            }
+            _ => String::new(),
+        };
+        Self { asset_type: asset.get_type(), id: asset.get_id().to_string(), state: asset.get_state(), details }
+    }
+
+    /// The live assets of Assets::all whose id contains `filter`, grouped by AssetType and sorted by id.
+    pub fn collect(filter: &str) -> Vec<AssetInfo> {
+        let mut infos: Vec<AssetInfo> = Assets::all()
+            .filter(|asset| ASSET_TYPES.contains(&asset.get_type()) && asset.get_id().contains(filter))
+            .map(|asset| AssetInfo::new(&asset))
+            .collect();
+        infos.sort_by(|a, b| (a.asset_type as u32, &a.id).cmp(&(b.asset_type as u32, &b.id)));
+        infos
+    }
+}
+
+/// The live asset of this type with this id.
+fn find_asset(asset_type: AssetType, id: &str) -> Option<Asset> {
+    match asset_type {
+        AssetType::Tex => Tex::find(id).ok().map(Asset::Tex),
+        AssetType::Mesh => Mesh::find(id).ok().map(Asset::Mesh),
+        AssetType::Material => Material::find(id).ok().map(Asset::Material),
+        AssetType::Model => Model::find(id).ok().map(Asset::Model),
+        AssetType::Sprite => Sprite::find(id).ok().map(Asset::Sprite),
+        _ => None,
+    }
+}
+
+/// A texture the assets are rendered in, with the Sprite showing it in the UI.
+struct Preview {
+    tex: Tex,
+    sprite: Sprite,
+    /// The material drawing a Tex asset on a quad.
+    material: Material,
+    /// Where the assets are drawn for this preview.
+    at: Vec3,
+}
+
+impl Preview {
+    fn new(id: &str, size: usize, at: Vec3) -> Result<Self, StereoKitError> {
+        let mut tex = Tex::render_target(size, size, None, Some(TexFormat::RGBA32), Some(TexFormat::Depth16))?;
+        tex.id(id);
+        let sprite = Sprite::from_tex(&tex, None, None)?;
+        let mut material = Material::unlit().copy();
+        material.id(id).face_cull(Cull::None);
+        Ok(Self { tex, sprite, material, at })
+    }
+
+    /// Draw the asset on RenderLayer::Layer9 at the place of this preview. Returns false if this type of asset has
+    /// no preview.
+    fn draw(&mut self, token: &MainThreadToken, asset: &Asset, rotation: Quat) -> bool {
+        let drawn = match asset {
+            // the previews can't be rendered in themselves
+            Asset::Tex(tex) if tex.get_id().starts_with(PREVIEW_ID) => false,
+            Asset::Tex(tex) => {
+                let (width, height) = (tex.get_width().unwrap_or(1) as f32, tex.get_height().unwrap_or(1) as f32);
+                let scale = Vec3::new(width, height, 1.0) / width.max(height);
+                self.material.diffuse_tex(tex);
+                // the quad faces Vec3::FORWARD, so we turn it toward the preview camera
+                let transform = Matrix::trs(&self.at, &Quat::from_angles(0.0, 180.0, 0.0), &scale);
+                Mesh::screen_quad().draw(token, &self.material, transform, None, Some(RenderLayer::Layer9));
+                true
+            }
+            Asset::Mesh(mesh) => {
+                let transform = fit(mesh.get_bounds(), rotation, self.at);
+                mesh.draw(token, Material::default(), transform, None, Some(RenderLayer::Layer9));
+                true
+            }
+            Asset::Material(material) => {
+                let transform = Matrix::r(rotation) * Matrix::t(self.at);
+                Mesh::sphere().draw(token, material, transform, None, Some(RenderLayer::Layer9));
+                true
+            }
+            Asset::Model(model) => {
+                model.draw(token, fit(model.get_bounds(), rotation, self.at), None, Some(RenderLayer::Layer9));
+                true
+            }
+            _ => false,
+        };
+        if !matches!(asset, Asset::Tex(_)) {
+            // release the previously previewed texture
+            self.material.diffuse_tex(Tex::white());
+        }
+        drawn
+    }
+
+    /// Render at the end of the frame what has been drawn at the place of this preview.
+    fn render(&self, token: &MainThreadToken) {
+        let camera_at = self.at + Vec3::new(0.0, 0.0, 1.5);
+        let camera = Matrix::tr(&camera_at, &Quat::look_at(camera_at, self.at, None));
+        let projection = Matrix::perspective(45.0, 1.0, 0.01, 10.0);
+        Renderer::render_to(
+            token,
+            &self.tex,
+            camera,
+            projection,
+            Some(RenderLayer::Layer9),
+            Some(RenderClear::All),
+            None,
+        );
+    }
+}
+
+/// A window listing the live assets grouped by type, filtered by id, with a live thumbnail of each Tex, Mesh,
+/// Material, Model or Sprite of the page and a larger preview of the selected one. The previews are rendered with
+/// Renderer::render_to in small textures, the assets being drawn on RenderLayer::Layer9 far below the scene.
+///
+/// The list only keeps the ids of the assets, so the browser doesn't keep them alive (except the ones of the page while
+/// they are drawn). It is refreshed every `refresh_period` and when the filter changes.
+pub struct AssetBrowser {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    enabled: bool,
+    pub pose: Pose,
+    /// The number of assets shown per page.
+    pub max_lines: usize,
+    /// How often the list of assets is refreshed.
+    pub refresh_period: Duration,
+    refreshed_at: Option<Instant>,
+    /// The listed type, all the types if None.
+    asset_type: Option<AssetType>,
+    filter: String,
+    page: usize,
+    infos: Vec<AssetInfo>,
+    selected: Option<(AssetType, String)>,
+    preview: Option<Preview>,
+    /// The thumbnails of the rows of the page, created when needed.
+    thumbnails: Vec<Preview>,
+    radio_off: Sprite,
+    radio_on: Sprite,
+}
+
+impl Default for AssetBrowser {
+    fn default() -> Self {
+        Self {
+            id: "AssetBrowser".to_string(),
+            sk_info: None,
+            enabled: false,
+            pose: Pose::new(Vec3::new(-0.7, 1.3, -0.3), Some(Quat::look_dir(Vec3::new(1.0, 0.0, 1.0)))),
+            max_lines: 15,
+            refresh_period: Duration::from_secs(1),
+            refreshed_at: None,
+            asset_type: None,
+            filter: String::new(),
+            page: 0,
+            infos: vec![],
+            selected: None,
+            preview: None,
+            thumbnails: vec![],
+            radio_off: Sprite::radio_off(),
+            radio_on: Sprite::radio_on(),
+        }
+    }
+}
+
+impl IStepper for AssetBrowser {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
+        self.id = id;
+        self.sk_info = Some(sk_info);
+
+        Ok(())
+    }
+
+    fn order(&self) -> StepperOrder {
+        StepperOrder::new(StepperPhase::Ui)
+    }
+
+    fn step(&mut self, token: &MainThreadToken) {
+        for (_, ShowAssetBrowser(show)) in token.get_events::<ShowAssetBrowser>() {
+            self.show(*show)
+        }
+        for (_, event) in token.get_events::<StringEvent>().filter(|(_, e)| e.key.eq(SHOW_ASSET_BROWSER)) {
+            self.show(event.get_bool().unwrap_or(false))
+        }
+        self.draw(token)
+    }
+
+    fn shutdown(&mut self) {
+        self.show(false);
+        self.preview = None;
+        self.thumbnails.clear();
+    }
+
+    fn save_state(&self) -> Option<StepperParams> {
+        let mut state = StepperParams::new();
+        state.set_pose("pose", self.pose).set("max_lines", self.max_lines).set("filter", &self.filter);
+        Some(state)
+    }
+
+    fn load_state(&mut self, state: &StepperParams) {
+        if let Some(pose) = state.get_pose("pose") {
+            self.pose = pose;
+        }
+        self.max_lines = state.get_or("max_lines", self.max_lines);
+        self.filter = state.get_or("filter", self.filter.clone());
+    }
+}
+
+impl AssetBrowser {
+    pub fn show(&mut self, value: bool) {
+        self.enabled = value;
+        self.refreshed_at = None;
+        if !value {
+            // release the previewed assets
+            self.selected = None;
+            self.infos.clear();
+            for preview in self.preview.iter_mut().chain(self.thumbnails.iter_mut()) {
+                preview.material.diffuse_tex(Tex::white());
+            }
+        }
+    }
+
+    fn draw(&mut self, token: &MainThreadToken) {
+        if !self.enabled {
+            return;
+        };
+
+        let stale = match self.refreshed_at {
+            Some(refreshed_at) => refreshed_at.elapsed() >= self.refresh_period,
+            None => true,
+        };
+        if stale {
+            self.infos = AssetInfo::collect(&self.filter);
+            self.refreshed_at = Some(Instant::now());
+        }
+
+        Ui::window_begin("Assets", &mut self.pose, Some(Vec2::new(60.0, 0.0) * CM), None, None);
+        Ui::label("Filter", None, true);
+        Ui::same_line();
+        if let Some(filter) = Ui::input("filter", &self.filter, Some(Vec2::new(30.0, 0.0) * CM), None) {
+            self.filter = filter;
+            self.page = 0;
+            self.refreshed_at = None;
+        }
+        Ui::same_line();
+        Ui::label(format!("{} assets", self.infos.len()), None, true);
+
+        if Ui::radio_img(
+            format!("All ({})", self.infos.len()),
+            self.asset_type.is_none(),
+            &self.radio_off,
+            &self.radio_on,
+            UiBtnLayout::Left,
+            None,
+        ) {
+            self.asset_type = None;
+            self.page = 0;
+        }
+        for (index, asset_type) in ASSET_TYPES.iter().enumerate() {
+            let count = self.infos.iter().filter(|info| info.asset_type == *asset_type).count();
+            if index % 5 != 4 {
+                Ui::same_line();
+            }
+            if Ui::radio_img(
+                format!("{:?} ({})", asset_type, count),
+                self.asset_type == Some(*asset_type),
+                &self.radio_off,
+                &self.radio_on,
+                UiBtnLayout::Left,
+                None,
+            ) {
+                self.asset_type = Some(*asset_type);
+                self.page = 0;
+            }
+        }
+        Ui::hseparator();
+        self.draw_list(token);
+        Ui::hseparator();
+        self.draw_preview(token);
+        Ui::window_end();
+    }
+
+    fn draw_list(&mut self, token: &MainThreadToken) {
+        // given back at the end, so the thumbnails can be drawn while the infos are listed
+        let infos = std::mem::take(&mut self.infos);
+        let listed: Vec<&AssetInfo> = infos
+            .iter()
+            .filter(|info| self.asset_type.is_none() || self.asset_type == Some(info.asset_type))
+            .collect();
+        let max_lines = self.max_lines.max(1);
+        let pages = listed.len().div_ceil(max_lines).max(1);
+        self.page = self.page.min(pages - 1);
+
+        // release the textures shown by the thumbnails at the previous frame
+        for thumbnail in self.thumbnails.iter_mut() {
+            thumbnail.material.diffuse_tex(Tex::white());
+        }
+        let thumbnail_size = Vec2::new(3.0, 3.0) * CM;
+        let mut selected = None;
+        for (line, info) in listed.iter().skip(self.page * max_lines).take(max_lines).enumerate() {
+            Ui::push_id(line.to_string());
+            if !self.draw_thumbnail(token, line, info, thumbnail_size) {
+                Ui::layout_reserve(thumbnail_size, false, 0.0);
+            }
+            Ui::same_line();
+            let is_selected = self.selected.as_ref().is_some_and(|(t, id)| *t == info.asset_type && *id == info.id);
+            let state = match info.state {
+                AssetState::Loaded => String::new(),
+                state => format!(" [{:?}]", state),
+            };
+            if Ui::radio_img(
+                format!("{:?} {}{}", info.asset_type, info.id, state),
+                is_selected,
+                &self.radio_off,
+                &self.radio_on,
+                UiBtnLayout::Left,
+                None,
+            ) {
+                selected = Some((info.asset_type, info.id.clone()));
+            }
+            if !info.details.is_empty() {
+                Ui::same_line();
+                Ui::label(&info.details, None, true);
+            }
+            Ui::pop_id();
+        }
+
+        if Ui::button("<", None) && self.page > 0 {
+            self.page -= 1;
+        }
+        Ui::same_line();
+        Ui::label(format!("{}/{}", self.page + 1, pages), None, true);
+        Ui::same_line();
+        if Ui::button(">", None) && self.page + 1 < pages {
+            self.page += 1;
+        }
+        if selected.is_some() {
+            self.selected = selected;
+        }
+        self.infos = infos;
+    }
+
+    /// Draw the thumbnail of the asset of this line of the page. Returns false if the asset has no thumbnail.
+    fn draw_thumbnail(&mut self, token: &MainThreadToken, line: usize, info: &AssetInfo, size: Vec2) -> bool {
+        let Some(asset) = find_asset(info.asset_type, &info.id) else { return false };
+        if let Asset::Sprite(sprite) = &asset {
+            Ui::image(sprite, size);
+            return true;
+        }
+        while self.thumbnails.len() <= line {
+            let index = self.thumbnails.len();
+            let at = PREVIEW_AT + Vec3::X * (THUMBNAIL_SPACING * (index + 1) as f32);
+            match Preview::new(&format!("{}{}", PREVIEW_ID, index), THUMBNAIL_SIZE, at) {
+                Ok(thumbnail) => self.thumbnails.push(thumbnail),
+                Err(error) => {
+                    Log::warn(format!("{}: no thumbnail: {}", self.id, error));
+                    return false;
+                }
+            }
+        }
+        let thumbnail = &mut self.thumbnails[line];
+        if !thumbnail.draw(token, &asset, Quat::from_angles(0.0, 30.0, 0.0)) {
+            return false;
+        }
+        thumbnail.render(token);
+        Ui::image(&thumbnail.sprite, size);
+        true
+    }
+
+    fn draw_preview(&mut self, token: &MainThreadToken) {
+        let Some((asset_type, id)) = self.selected.clone() else {
+            Ui::label("Select an asset to preview it", None, true);
+            return;
+        };
+        let Some(asset) = find_asset(asset_type, &id) else {
+            Ui::label(format!("No preview for {}, it has been released or it is a {:?}", id, asset_type), None, true);
+            return;
+        };
+        let size = Vec2::new(20.0, 20.0) * CM;
+        if let Asset::Sprite(sprite) = &asset {
+            Ui::image(sprite, size);
+            return;
+        }
+        if self.preview.is_none() {
+            match Preview::new(PREVIEW_ID, PREVIEW_SIZE, PREVIEW_AT) {
+                Ok(preview) => self.preview = Some(preview),
+                Err(error) => {
+                    Log::warn(format!("{}: no preview: {}", self.id, error));
+                    self.selected = None;
+                    return;
+                }
+            }
+        }
+        let Some(preview) = &mut self.preview else { return };
+        let rotation = Quat::from_angles(0.0, Time::get_totalf() * 30.0, 0.0);
+        if !preview.draw(token, &asset, rotation) {
+            Ui::label(format!("No preview for {:?}", asset_type), None, true);
+            return;
+        }
+        preview.render(token);
+        Ui::image(&preview.sprite, size);
+    }
+}
+
+/// A transform fitting the bounds in a 1 meter cube centered on `at`.
+fn fit(bounds: Bounds, rotation: Quat, at: Vec3) -> Matrix {
+    let scale = 1.0 / bounds.dimensions.x.max(bounds.dimensions.y).max(bounds.dimensions.z).max(0.0001);
+    Matrix::t(bounds.center * -1.0) * Matrix::s(Vec3::ONE * scale) * Matrix::r(rotation) * Matrix::t(at)
+}
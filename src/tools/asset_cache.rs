@@ -0,0 +1,355 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    model::Model,
+    shader::Shader,
+    sound::Sound,
+    system::{Asset, AssetType, Assets, IAsset},
+    tex::{Tex, TexFormat},
+    StereoKitError,
+};
+
+/// When the [`AssetCache`] releases its entries. The handles already given by the cache stay valid, only the cache
+/// forgets them (so the next request loads the file again if StereoKit released the asset meanwhile).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EvictionPolicy {
+    /// Entries are only released by AssetCache::evict and AssetCache::clear.
+    #[default]
+    Manual,
+    /// Keep at most this number of entries, the least recently used are released first.
+    MaxEntries(usize),
+    /// Release the entries not requested since this duration.
+    MaxAge(Duration),
+    /// Keep the texture memory of the cached Tex under this number of bytes, the least recently used are released
+    /// first. The memory is a rough estimate counting the block compressed formats as 4 bytes per pixel.
+    TexMemory(usize),
+}
+
+/// The statistics of an [`AssetCache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub entries: usize,
+    /// The requests answered by the cache.
+    pub hits: u64,
+    /// The requests that loaded a file.
+    pub misses: u64,
+    pub evictions: u64,
+    /// The entries whose asset is also the asset of an entry with other options: StereoKit gives back the asset
+    /// already loaded from a file, so the options of the later requests had no effect. See [`AssetCache::get_entries`].
+    pub shared: usize,
+    /// A rough estimate of the texture memory of the cached Tex, in bytes. See [`EvictionPolicy::TexMemory`].
+    pub tex_memory: usize,
+}
+
+/// The statistics of all the assets of a type currently loaded by StereoKit. See [`AssetStats::collect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetStats {
+    pub asset_type: AssetType,
+    pub count: usize,
+    /// For AssetType::Tex, a rough estimate of the memory of the textures in bytes (with their mips). The block
+    /// compressed formats are counted as 4 bytes per pixel.
+    pub tex_memory: usize,
+    /// For AssetType::Mesh, the number of vertices of all the meshes.
+    pub vertex_count: usize,
+    /// For AssetType::Mesh, the number of indices of all the meshes.
+    pub index_count: usize,
+}
+
+impl AssetStats {
+    /// The statistics of each type of asset currently loaded, in the AssetType order.
+    pub fn collect() -> Vec<AssetStats> {
+        let mut stats: Vec<AssetStats> = vec![];
+        for asset in Assets::all() {
+            let asset_type = asset.get_type();
+            if asset_type == AssetType::None {
+                continue;
+            }
+            let index = match stats.iter().position(|stat| stat.asset_type == asset_type) {
+                Some(index) => index,
+                None => {
+                    stats.push(AssetStats { asset_type, count: 0, tex_memory: 0, vertex_count: 0, index_count: 0 });
+                    stats.len() - 1
+                }
+            };
+            let stat = &mut stats[index];
+            stat.count += 1;
+            match &asset {
+                Asset::Tex(tex) => stat.tex_memory += tex_memory(tex),
+                Asset::Mesh(mesh) => {
+                    stat.vertex_count += mesh.get_vert_count().max(0) as usize;
+                    stat.index_count += mesh.get_ind_count().max(0) as usize;
+                }
+                _ => {}
+            }
+        }
+        stats.sort_by_key(|stat| stat.asset_type as u32);
+        stats
+    }
+}
+
+/// An entry of an [`AssetCache`], see [`AssetCache::get_entries`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedAsset {
+    pub asset_type: AssetType,
+    pub path: PathBuf,
+    /// The loading options, as a string.
+    pub options: String,
+    /// The id of the asset. Entries of the same file with other options share the asset when they have the same id.
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    asset_type: AssetType,
+    path: PathBuf,
+    /// The loading options, as a string.
+    options: String,
+}
+
+struct CacheEntry {
+    /// A reference to the asset, keeping it alive.
+    asset: Asset,
+    last_used: Instant,
+}
+
+impl CacheEntry {
+    fn get_id(&self) -> &str {
+        self.asset.get_id()
+    }
+
+    fn get_tex_memory(&self) -> usize {
+        match &self.asset {
+            Asset::Tex(tex) => tex_memory(tex),
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Default)]
+struct AssetCacheData {
+    policy: EvictionPolicy,
+    entries: HashMap<CacheKey, CacheEntry>,
+    stats: CacheStats,
+}
+
+/// A cache of the assets loaded from files, so the steppers loading the same file share the same asset. Get it with
+/// Sk::get_asset_cache or SkInfo::get_asset_cache.
+///
+/// Entries are keyed by asset type, path and loading options. StereoKit gives back the asset already loaded from a
+/// file whatever the options, so two entries of the same file may share their asset (a Tex requested with srgb_data
+/// false after being loaded with true is still the sRGB one). These entries are counted in CacheStats::shared.
+/// ```ignore
+/// let cache = sk_info.borrow().get_asset_cache();
+/// cache.policy(EvictionPolicy::TexMemory(256 * 1024 * 1024));
+/// let floor = cache.tex("textures/parquet2/parquet2.ktx2", true, None)?;
+/// let plane = cache.model("plane.glb", None)?;
+/// Log::diag(format!("{:?}", cache.get_stats()));
+/// ```
+#[derive(Default, Clone)]
+pub struct AssetCache {
+    data: Rc<RefCell<AssetCacheData>>,
+}
+
+impl fmt::Debug for AssetCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = self.data.borrow();
+        write!(f, "AssetCache {:?} with {} entries", data.policy, data.entries.len())
+    }
+}
+
+impl AssetCache {
+    /// Change the eviction policy, the entries over the new limits are released now.
+    pub fn policy(&self, policy: EvictionPolicy) {
+        self.data.borrow_mut().policy = policy;
+        self.trim();
+    }
+
+    pub fn get_policy(&self) -> EvictionPolicy {
+        self.data.borrow().policy
+    }
+
+    /// A texture from the cache or from the file. See Tex::from_file
+    pub fn tex(&self, path: impl AsRef<Path>, srgb_data: bool, priority: Option<i32>) -> Result<Tex, StereoKitError> {
+        let path = path.as_ref();
+        let options = format!("srgb:{} priority:{:?}", srgb_data, priority);
+        self.get_or_load(path, options, || Tex::from_file(path, srgb_data, priority), Asset::Tex)
+    }
+
+    /// A model from the cache or from the file. See Model::from_file
+    pub fn model(&self, path: impl AsRef<Path>, shader: Option<Shader>) -> Result<Model, StereoKitError> {
+        let path = path.as_ref();
+        let options = format!("shader:{}", shader.as_ref().map(|shader| shader.get_id()).unwrap_or_default());
+        self.get_or_load(path, options, || Model::from_file(path, shader), Asset::Model)
+    }
+
+    /// A sound from the cache or from the file. See Sound::from_file
+    pub fn sound(&self, path: impl AsRef<Path>) -> Result<Sound, StereoKitError> {
+        let path = path.as_ref();
+        self.get_or_load(path, String::new(), || Sound::from_file(path), Asset::Sound)
+    }
+
+    /// A shader from the cache or from the file. See Shader::from_file
+    pub fn shader(&self, path: impl AsRef<Path>) -> Result<Shader, StereoKitError> {
+        let path = path.as_ref();
+        self.get_or_load(path, String::new(), || Shader::from_file(path), Asset::Shader)
+    }
+
+    /// Release the entries of this file, one per asset type and options. Returns the number of entries released.
+    pub fn evict(&self, path: impl AsRef<Path>) -> usize {
+        let path = path.as_ref();
+        let mut data = self.data.borrow_mut();
+        let before = data.entries.len();
+        data.entries.retain(|key, _| key.path != path);
+        let evicted = before - data.entries.len();
+        data.stats.evictions += evicted as u64;
+        evicted
+    }
+
+    /// Release all the entries.
+    pub fn clear(&self) {
+        let mut data = self.data.borrow_mut();
+        data.stats.evictions += data.entries.len() as u64;
+        data.entries.clear();
+    }
+
+    /// Apply the eviction policy. This is done at each load, call it if you use EvictionPolicy::MaxAge and don't load
+    /// often.
+    pub fn trim(&self) {
+        let mut data = self.data.borrow_mut();
+        let mut by_age: Vec<(CacheKey, Instant, usize)> = data
+            .entries
+            .iter()
+            .map(|(key, entry)| (key.clone(), entry.last_used, entry.get_tex_memory()))
+            .collect();
+        by_age.sort_by_key(|(_, last_used, _)| *last_used);
+        let mut evicted: Vec<CacheKey> = vec![];
+        match data.policy {
+            EvictionPolicy::Manual => {}
+            EvictionPolicy::MaxEntries(max) => {
+                let over = by_age.len().saturating_sub(max);
+                evicted.extend(by_age.into_iter().take(over).map(|(key, _, _)| key));
+            }
+            EvictionPolicy::MaxAge(max_age) => {
+                let now = Instant::now();
+                evicted.extend(
+                    by_age.into_iter().filter(|(_, last_used, _)| now - *last_used > max_age).map(|(key, _, _)| key),
+                );
+            }
+            EvictionPolicy::TexMemory(max) => {
+                let mut memory: usize = by_age.iter().map(|(_, _, tex_memory)| tex_memory).sum();
+                for (key, _, tex_memory) in by_age {
+                    if memory <= max {
+                        break;
+                    }
+                    if tex_memory > 0 {
+                        memory -= tex_memory;
+                        evicted.push(key);
+                    }
+                }
+            }
+        }
+        data.stats.evictions += evicted.len() as u64;
+        for key in evicted {
+            data.entries.remove(&key);
+        }
+    }
+
+    pub fn get_stats(&self) -> CacheStats {
+        let data = self.data.borrow();
+        let shared = data
+            .entries
+            .iter()
+            .filter(|(key, entry)| {
+                data.entries.iter().any(|(other_key, other)| {
+                    other_key.asset_type == key.asset_type
+                        && other_key.options != key.options
+                        && other.get_id() == entry.get_id()
+                })
+            })
+            .count();
+        CacheStats {
+            entries: data.entries.len(),
+            shared,
+            tex_memory: data.entries.values().map(|entry| entry.get_tex_memory()).sum(),
+            ..data.stats
+        }
+    }
+
+    /// The cached entries, sorted by type, path and options.
+    pub fn get_entries(&self) -> Vec<CachedAsset> {
+        let data = self.data.borrow();
+        let mut entries: Vec<CachedAsset> = data
+            .entries
+            .iter()
+            .map(|(key, entry)| CachedAsset {
+                asset_type: key.asset_type,
+                path: key.path.clone(),
+                options: key.options.clone(),
+                id: entry.get_id().to_string(),
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (a.asset_type as u32, &a.path, &a.options).cmp(&(b.asset_type as u32, &b.path, &b.options))
+        });
+        entries
+    }
+
+    fn get_or_load<T: IAsset>(
+        &self,
+        path: &Path,
+        options: String,
+        load: impl FnOnce() -> Result<T, StereoKitError>,
+        wrap: fn(T) -> Asset,
+    ) -> Result<T, StereoKitError> {
+        let key = CacheKey { asset_type: T::ASSET_TYPE, path: path.to_path_buf(), options };
+        {
+            let mut data = self.data.borrow_mut();
+            let AssetCacheData { entries, stats, .. } = &mut *data;
+            if let Some(entry) = entries.get_mut(&key) {
+                // find gives a new reference to the cached asset
+                if let Ok(asset) = T::find(entry.get_id()) {
+                    entry.last_used = Instant::now();
+                    stats.hits += 1;
+                    return Ok(asset);
+                }
+            }
+        }
+
+        let asset = load()?;
+        let cached = wrap(T::find(asset.get_id())?);
+        {
+            let mut data = self.data.borrow_mut();
+            data.stats.misses += 1;
+            data.entries.insert(key, CacheEntry { asset: cached, last_used: Instant::now() });
+        }
+        self.trim();
+        Ok(asset)
+    }
+}
+
+/// A rough estimate of the GPU memory of a texture, 0 while its size is not known yet. The formats StereoKit doesn't
+/// report, like the block compressed ones of KTX2 files, are counted as 4 bytes per pixel, so they are over-estimated.
+fn tex_memory(tex: &Tex) -> usize {
+    let (Some(width), Some(height)) = (tex.get_width(), tex.get_height()) else { return 0 };
+    let bytes_per_pixel = match tex.get_format() {
+        Some(TexFormat::R8) => 1,
+        Some(TexFormat::R16u | TexFormat::R16s | TexFormat::R16f | TexFormat::Depth16 | TexFormat::R8G8) => 2,
+        Some(TexFormat::RGBA64U | TexFormat::RGBA64S | TexFormat::RGBA64F) => 8,
+        Some(TexFormat::RGBA128) => 16,
+        _ => 4,
+    };
+    let size = width * height * bytes_per_pixel;
+    // the mip chain adds a third of the size
+    if tex.get_mips().unwrap_or(1) > 1 {
+        size + size / 3
+    } else {
+        size
+    }
+}
@@ -0,0 +1,277 @@
+use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
+
+use crate::{
+    system::{AssetType, Assets, Log},
+    tools::asset_cache::AssetCache,
+};
+
+/// An asset seen by an [`AssetSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssetRecord {
+    pub asset_type: AssetType,
+    pub id: String,
+}
+
+impl fmt::Display for AssetRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} : {}", self.asset_type, self.id)
+    }
+}
+
+/// The live assets of StereoKit at a given moment, counted by type and id. Compare two snapshots to find the assets
+/// created and not released between them. This doesn't need the steppers, so it can be used from headless tests:
+/// ```ignore
+/// let before = AssetSnapshot::take();
+/// load_and_unload_level();
+/// let leaks = AssetSnapshot::take().created_since(&before);
+/// assert!(leaks.is_empty(), "{:?}", leaks);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetSnapshot {
+    records: HashMap<AssetRecord, usize>,
+}
+
+impl AssetSnapshot {
+    /// A snapshot of Assets::all.
+    pub fn take() -> Self {
+        let mut records = HashMap::new();
+        for asset in Assets::all() {
+            let record = AssetRecord { asset_type: asset.get_type(), id: asset.get_id().to_string() };
+            *records.entry(record).or_insert(0) += 1;
+        }
+        Self { records }
+    }
+
+    /// The number of assets of the snapshot.
+    pub fn get_count(&self) -> usize {
+        self.records.values().sum()
+    }
+
+    /// The assets of this snapshot that were not in the `earlier` one, sorted by type and id. An id used by several
+    /// assets is given once per asset created.
+    pub fn created_since(&self, earlier: &AssetSnapshot) -> Vec<AssetRecord> {
+        Self::difference(self, earlier)
+    }
+
+    /// The assets of the `earlier` snapshot that are not in this one anymore, sorted by type and id.
+    pub fn released_since(&self, earlier: &AssetSnapshot) -> Vec<AssetRecord> {
+        Self::difference(earlier, self)
+    }
+
+    /// The ids used by several assets of the same type, with the number of assets using them.
+    pub fn get_collisions(&self) -> Vec<(AssetRecord, usize)> {
+        let mut collisions: Vec<(AssetRecord, usize)> = self
+            .records
+            .iter()
+            .filter(|(_, count)| **count > 1)
+            .map(|(record, count)| (record.clone(), *count))
+            .collect();
+        collisions.sort_by(|(a, _), (b, _)| (a.asset_type as u32, &a.id).cmp(&(b.asset_type as u32, &b.id)));
+        collisions
+    }
+
+    fn difference(from: &AssetSnapshot, minus: &AssetSnapshot) -> Vec<AssetRecord> {
+        let mut records = vec![];
+        for (record, count) in from.records.iter() {
+            let before = minus.records.get(record).copied().unwrap_or(0);
+            for _ in before..*count {
+                records.push(record.clone());
+            }
+        }
+        records.sort_by(|a, b| (a.asset_type as u32, &a.id).cmp(&(b.asset_type as u32, &b.id)));
+        records
+    }
+}
+
+struct AssetLeaksData {
+    enabled: bool,
+    on_scene_change: bool,
+    on_shutdown: bool,
+    ignored: Vec<String>,
+    baseline: AssetSnapshot,
+    /// The snapshots taken when the scenes of the stack were pushed, by scene serial.
+    scenes: HashMap<u64, AssetSnapshot>,
+    leaks: Vec<AssetRecord>,
+}
+
+impl Default for AssetLeaksData {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            on_scene_change: true,
+            on_shutdown: true,
+            ignored: vec![],
+            baseline: AssetSnapshot::default(),
+            scenes: HashMap::new(),
+            leaks: vec![],
+        }
+    }
+}
+
+/// A diagnostics mode reporting with Log::warn the assets created and never released, and the ids used by several
+/// assets of the same type. Once enabled, the checks are done:
+/// * when a scene is popped or replaced: the assets created since the scene was pushed and still alive once its
+///   steppers are removed. The steppers of a scene are usually constructed before it is pushed, give the scene a
+///   snapshot taken before with Scene::track_assets so the assets of their constructors are checked too. On a
+///   replace, the assets created since the snapshot of the incoming scene are not reported for the outgoing one.
+/// * when the steppers are shut down: the assets created since the mode was enabled and still alive once all the
+///   steppers are removed.
+/// * when AssetLeaks::check is called.
+///
+/// The scene and shutdown checks don't report the assets kept by the [`AssetCache`]. Get it with
+/// Sk::get_asset_leaks or SkInfo::get_asset_leaks.
+/// ```ignore
+/// let leaks = sk.get_asset_leaks();
+/// leaks.ignore("ui/").enable(true);
+/// let mut scene = Scene::new("Level1");
+/// scene.track_assets(leaks.scene_snapshot()).add_default::<Biplane1>("Biplane1");
+/// sk.replace_scene(scene);
+/// sk.run(|_| {}, |_| {});
+/// assert!(leaks.get_leaks().is_empty());
+/// ```
+#[derive(Default, Clone)]
+pub struct AssetLeaks {
+    data: Rc<RefCell<AssetLeaksData>>,
+}
+
+impl fmt::Debug for AssetLeaks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = self.data.borrow();
+        write!(f, "AssetLeaks enabled: {} with {} leaks", data.enabled, data.leaks.len())
+    }
+}
+
+impl AssetLeaks {
+    /// Enable or disable the checks. Enabling takes the snapshot the next checks are compared to.
+    pub fn enable(&self, enabled: bool) -> &Self {
+        let mut data = self.data.borrow_mut();
+        if enabled && !data.enabled {
+            data.baseline = AssetSnapshot::take();
+        }
+        data.enabled = enabled;
+        self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.data.borrow().enabled
+    }
+
+    /// Check the scenes when they are popped or replaced. Default is true.
+    pub fn on_scene_change(&self, check: bool) -> &Self {
+        self.data.borrow_mut().on_scene_change = check;
+        self
+    }
+
+    /// Check when the steppers are shut down. Default is true.
+    pub fn on_shutdown(&self, check: bool) -> &Self {
+        self.data.borrow_mut().on_shutdown = check;
+        self
+    }
+
+    /// Don't report the assets whose id starts with this prefix.
+    pub fn ignore(&self, id_prefix: impl AsRef<str>) -> &Self {
+        self.data.borrow_mut().ignored.push(id_prefix.as_ref().to_string());
+        self
+    }
+
+    /// The snapshot a scene is checked against when it is popped, to be given to Scene::track_assets before its
+    /// steppers are constructed. This walks the asset list of StereoKit. None if the scenes are not checked.
+    pub fn scene_snapshot(&self) -> Option<AssetSnapshot> {
+        let data = self.data.borrow();
+        (data.enabled && data.on_scene_change).then(AssetSnapshot::take)
+    }
+
+    /// Take a new snapshot the next checks will be compared to.
+    pub fn checkpoint(&self) {
+        self.data.borrow_mut().baseline = AssetSnapshot::take();
+    }
+
+    /// Report the assets created since the mode was enabled (or since the last checkpoint) and still alive. Returns
+    /// them, even if the mode is disabled.
+    pub fn check(&self, label: impl AsRef<str>) -> Vec<AssetRecord> {
+        let baseline = self.data.borrow().baseline.clone();
+        self.report(label.as_ref(), &baseline, None, None)
+    }
+
+    /// The assets reported by the last check.
+    pub fn get_leaks(&self) -> Vec<AssetRecord> {
+        self.data.borrow().leaks.clone()
+    }
+
+    /// A scene is pushed, before the initialization of its steppers. `tracked` is the snapshot given to
+    /// Scene::track_assets, if any.
+    pub(crate) fn scene_pushed(&self, serial: u64, tracked: Option<AssetSnapshot>) {
+        let mut data = self.data.borrow_mut();
+        if data.enabled && data.on_scene_change {
+            data.scenes.insert(serial, tracked.unwrap_or_else(AssetSnapshot::take));
+        }
+    }
+
+    /// A scene has been popped and its steppers removed. `incoming` is the tracked snapshot of the scene replacing
+    /// it, the assets created since then belong to the incoming scene.
+    pub(crate) fn scene_popped(
+        &self,
+        serial: u64,
+        name: &str,
+        asset_cache: &AssetCache,
+        incoming: Option<&AssetSnapshot>,
+    ) {
+        let Some(pushed) = self.data.borrow_mut().scenes.remove(&serial) else { return };
+        if self.is_enabled() {
+            self.report(&format!("scene {}", name), &pushed, Some(asset_cache), incoming);
+        }
+    }
+
+    /// All the steppers have been shut down and removed.
+    pub(crate) fn steppers_shut_down(&self, asset_cache: &AssetCache) {
+        let (check, baseline) = {
+            let mut data = self.data.borrow_mut();
+            data.scenes.clear();
+            (data.enabled && data.on_shutdown, data.baseline.clone())
+        };
+        if check {
+            self.report("shutdown", &baseline, Some(asset_cache), None);
+        }
+    }
+
+    fn report(
+        &self,
+        label: &str,
+        earlier: &AssetSnapshot,
+        asset_cache: Option<&AssetCache>,
+        excluded: Option<&AssetSnapshot>,
+    ) -> Vec<AssetRecord> {
+        let now = AssetSnapshot::take();
+        let mut excluded = excluded.map(|excluded| now.created_since(excluded)).unwrap_or_default();
+        let cached: Vec<AssetRecord> = asset_cache
+            .map(|asset_cache| asset_cache.get_entries())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| AssetRecord { asset_type: entry.asset_type, id: entry.id })
+            .collect();
+        let mut data = self.data.borrow_mut();
+        let reported = |record: &AssetRecord| {
+            !cached.contains(record) && !data.ignored.iter().any(|prefix| record.id.starts_with(prefix))
+        };
+        let leaks: Vec<AssetRecord> = now
+            .created_since(earlier)
+            .into_iter()
+            .filter(|record| match excluded.iter().position(|excluded| excluded == record) {
+                Some(index) => {
+                    excluded.swap_remove(index);
+                    false
+                }
+                None => true,
+            })
+            .filter(reported)
+            .collect();
+        for record in &leaks {
+            Log::warn(format!("Asset leak ({}): {} was created and never released", label, record));
+        }
+        for (record, count) in now.get_collisions().into_iter().filter(|(record, _)| reported(record)) {
+            Log::warn(format!("Asset leak ({}): the id of {} is used by {} assets", label, record, count));
+        }
+        data.leaks = leaks.clone();
+        leaks
+    }
+}
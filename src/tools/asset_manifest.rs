@@ -0,0 +1,467 @@
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use crate::{
+    event_loop::{IStepper, Scene, Schedule, StepperAction, StepperId, StepperOrder, StepperPhase},
+    maths::{units::CM, Pose, Quat, Vec2, Vec3},
+    model::Model,
+    sk::{MainThreadToken, SkInfo},
+    sound::Sound,
+    system::{AssetLoad, AssetState, AssetType, Assets, IAsset, Log},
+    tex::Tex,
+    ui::Ui,
+    StereoKitError,
+};
+
+/// An asset to preload, see [`AssetManifest`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub path: PathBuf,
+    /// AssetType::Tex, AssetType::Model or AssetType::Sound.
+    pub asset_type: AssetType,
+    /// The id given to the asset so it can be found with Tex::find, Model::find or Sound::find. None keeps the id
+    /// StereoKit gives (the file name).
+    pub id: Option<String>,
+    /// Lower priorities are loaded first. Only textures are loaded in the background, the other assets are loaded
+    /// when the preload starts, in priority order.
+    pub priority: i32,
+    /// For textures, is the data in the sRGB color space?
+    pub srgb: bool,
+}
+
+impl ManifestEntry {
+    /// A texture with priority 10 and sRGB data.
+    pub fn tex(path: impl AsRef<Path>) -> Self {
+        Self { path: path.as_ref().to_path_buf(), asset_type: AssetType::Tex, id: None, priority: 10, srgb: true }
+    }
+
+    pub fn model(path: impl AsRef<Path>) -> Self {
+        Self { asset_type: AssetType::Model, ..Self::tex(path) }
+    }
+
+    pub fn sound(path: impl AsRef<Path>) -> Self {
+        Self { asset_type: AssetType::Sound, ..Self::tex(path) }
+    }
+
+    pub fn id(mut self, id: impl AsRef<str>) -> Self {
+        self.id = Some(id.as_ref().to_string());
+        self
+    }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn srgb(mut self, srgb: bool) -> Self {
+        self.srgb = srgb;
+        self
+    }
+}
+
+/// A list of assets to load before starting a scene. Build it in code or read it from a TOML file:
+/// ```toml
+/// name = "Demo assets"
+///
+/// [[asset]]
+/// path = "textures/parquet2/parquet2.ktx2"
+/// # tex, model or sound
+/// type = "tex"
+/// # all the following fields are optional
+/// id = "parquet"
+/// priority = 5
+/// srgb = true
+///
+/// [[asset]]
+/// path = "plane.glb"
+/// type = "model"
+/// ```
+/// Then show a [`LoadingScreen`] or call [`AssetManifest::preload`] and follow the [`AssetPreload`] yourself.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetManifest {
+    pub name: String,
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl AssetManifest {
+    pub fn new(name: impl AsRef<str>) -> Self {
+        Self { name: name.as_ref().to_string(), entries: vec![] }
+    }
+
+    pub fn add(&mut self, entry: ManifestEntry) -> &mut Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Read a manifest file. See [`AssetManifest`] for the format.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StereoKitError> {
+        let path = path.as_ref();
+        let invalid = |reason: String| StereoKitError::AssetManifest(path.to_path_buf(), reason);
+        let text = fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        Self::from_toml(&text).map_err(|e| match e {
+            StereoKitError::AssetManifest(_, reason) => invalid(reason),
+            e => e,
+        })
+    }
+
+    /// Read a TOML manifest. See [`AssetManifest`] for the format.
+    pub fn from_toml(text: &str) -> Result<Self, StereoKitError> {
+        let invalid = |reason: String| StereoKitError::AssetManifest(PathBuf::new(), reason);
+        let table: toml::Table = text.parse().map_err(|e: toml::de::Error| invalid(e.message().to_string()))?;
+        let mut manifest = Self::new(table.get("name").and_then(|v| v.as_str()).unwrap_or("assets"));
+        let assets = match table.get("asset") {
+            Some(toml::Value::Array(assets)) => assets.as_slice(),
+            Some(_) => return Err(invalid("asset must be an array of tables [[asset]]".into())),
+            None => &[],
+        };
+        for asset in assets {
+            let Some(path) = asset.get("path").and_then(|v| v.as_str()) else {
+                return Err(invalid("an [[asset]] has no path".into()));
+            };
+            let mut entry = match asset.get("type").and_then(|v| v.as_str()) {
+                Some("tex") => ManifestEntry::tex(path),
+                Some("model") => ManifestEntry::model(path),
+                Some("sound") => ManifestEntry::sound(path),
+                other => return Err(invalid(format!("type of {} must be tex, model or sound, not {:?}", path, other))),
+            };
+            if let Some(id) = asset.get("id").and_then(|v| v.as_str()) {
+                entry = entry.id(id);
+            }
+            if let Some(priority) = asset.get("priority").and_then(|v| v.as_integer()) {
+                let priority = i32::try_from(priority)
+                    .map_err(|_| invalid(format!("priority {} of {} is out of range", priority, path)))?;
+                entry = entry.priority(priority);
+            }
+            if let Some(srgb) = asset.get("srgb").and_then(|v| v.as_bool()) {
+                entry = entry.srgb(srgb);
+            }
+            manifest.add(entry);
+        }
+        Ok(manifest)
+    }
+
+    /// Start loading all the assets, the lowest priorities first.
+    pub fn preload(&self) -> AssetPreload {
+        AssetPreload::new(self)
+    }
+}
+
+/// An asset created by an [`AssetPreload`].
+pub enum PreloadedAsset {
+    Tex(AssetLoad<Tex>),
+    Model(AssetLoad<Model>),
+    Sound(AssetLoad<Sound>),
+}
+
+impl PreloadedAsset {
+    pub fn get_state(&self) -> AssetState {
+        match self {
+            PreloadedAsset::Tex(load) => load.get_state(),
+            PreloadedAsset::Model(load) => load.get_state(),
+            PreloadedAsset::Sound(load) => load.get_state(),
+        }
+    }
+
+    /// None while loading. An asset that couldn't be created is reported as not found.
+    fn get_result(&self) -> Option<Result<(), StereoKitError>> {
+        fn result<T: IAsset>(load: &AssetLoad<T>) -> Option<Result<(), StereoKitError>> {
+            let path = load.get_path();
+            match load.get_asset() {
+                Some(asset) => asset.get_asset_state().to_result(asset.get_id(), path),
+                None => Some(Err(StereoKitError::AssetNotFound(path.display().to_string(), path.to_path_buf()))),
+            }
+        }
+        match self {
+            PreloadedAsset::Tex(load) => result(load),
+            PreloadedAsset::Model(load) => result(load),
+            PreloadedAsset::Sound(load) => result(load),
+        }
+    }
+}
+
+/// The assets of an [`AssetManifest`] being loaded. The assets are kept alive as long as the AssetPreload, so keep it
+/// until the steppers using them have found them.
+pub struct AssetPreload {
+    name: String,
+    assets: Vec<(ManifestEntry, PreloadedAsset)>,
+    first_task: i32,
+    last_task: i32,
+}
+
+impl AssetPreload {
+    fn new(manifest: &AssetManifest) -> Self {
+        let first_task = Assets::current_task();
+        let mut entries = manifest.entries.clone();
+        entries.sort_by_key(|entry| entry.priority);
+        let assets = entries
+            .into_iter()
+            .map(|entry| {
+                let asset = match entry.asset_type {
+                    AssetType::Model => {
+                        let mut model = Model::from_file(&entry.path, None);
+                        if let (Ok(model), Some(id)) = (model.as_mut(), &entry.id) {
+                            model.id(id);
+                        }
+                        PreloadedAsset::Model(AssetLoad::new(model, &entry.path))
+                    }
+                    AssetType::Sound => {
+                        let mut sound = Sound::from_file(&entry.path);
+                        if let (Ok(sound), Some(id)) = (sound.as_mut(), &entry.id) {
+                            sound.id(id);
+                        }
+                        PreloadedAsset::Sound(AssetLoad::new(sound, &entry.path))
+                    }
+                    _ => {
+                        let mut tex = Tex::from_file(&entry.path, entry.srgb, Some(entry.priority));
+                        if let (Ok(tex), Some(id)) = (tex.as_mut(), &entry.id) {
+                            tex.id(id);
+                        }
+                        PreloadedAsset::Tex(AssetLoad::new(tex, &entry.path))
+                    }
+                };
+                (entry, asset)
+            })
+            .collect();
+        Self { name: manifest.name.clone(), assets, first_task, last_task: Assets::total_tasks() }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The number of assets of the manifest.
+    pub fn get_count(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// The number of assets loaded or that failed to load.
+    pub fn get_finished_count(&self) -> usize {
+        self.assets.iter().filter(|(_, asset)| asset.get_state().is_finished()).count()
+    }
+
+    /// true once all the assets are Loaded or failed to load.
+    pub fn is_finished(&self) -> bool {
+        self.assets.iter().all(|(_, asset)| asset.get_state().is_finished())
+    }
+
+    /// The overall progress from 0.0 to 1.0, mixing the finished assets and the loading tasks of StereoKit queued by
+    /// the preload (Assets::current_task / Assets::total_tasks).
+    pub fn get_progress(&self) -> f32 {
+        if self.is_finished() {
+            return 1.0;
+        }
+        let assets = self.get_finished_count() as f32 / self.assets.len() as f32;
+        let queued = self.last_task - self.first_task;
+        if queued <= 0 {
+            return assets;
+        }
+        let tasks = ((Assets::current_task() - self.first_task) as f32 / queued as f32).clamp(0.0, 1.0);
+        (assets + tasks) / 2.0
+    }
+
+    /// The errors of the assets that failed to load.
+    pub fn get_errors(&self) -> Vec<StereoKitError> {
+        self.assets.iter().filter_map(|(_, asset)| asset.get_result()?.err()).collect()
+    }
+
+    /// The entries with their assets, sorted by priority.
+    pub fn get_assets(&self) -> impl Iterator<Item = &(ManifestEntry, PreloadedAsset)> {
+        self.assets.iter()
+    }
+
+    /// Block the app until the assets whose priority is below this one are loaded. See Assets::block_for_priority.
+    pub fn block_for_priority(&self, priority: i32) {
+        Assets::block_for_priority(priority)
+    }
+
+    /// Block the app until all the assets are loaded.
+    pub fn block(&self) {
+        let last = self.assets.iter().map(|(entry, _)| entry.priority).max().unwrap_or(0);
+        Assets::block_for_priority(last.saturating_add(1))
+    }
+}
+
+/// The typed event sent by a [`LoadingScreen`] when all the assets of its manifest are loaded or failed to load.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetsPreloaded {
+    /// The name of the manifest.
+    pub name: String,
+    pub count: usize,
+    pub errors: usize,
+}
+
+/// A window with a progress bar shown while the assets of a manifest are loading. Once they are all finished it sends
+/// an [`AssetsPreloaded`] event then replaces the current scene with the next scene if any. The preloaded assets are
+/// then kept by the next scene, so its steppers can find them, until the scene is removed. Without a next scene the
+/// loading screen disables itself and keeps the assets until it is removed.
+/// ```ignore
+/// let manifest = AssetManifest::load("assets/manifests/demo.toml")?;
+/// let mut loading = Scene::new("Loading");
+/// loading.add("LoadingScreen", LoadingScreen::new(&manifest, Some(demo_scene)));
+/// sk.push_scene(loading);
+/// ```
+pub struct LoadingScreen {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    manifest: AssetManifest,
+    preload: Option<AssetPreload>,
+    kept: Option<AssetPreload>,
+    next_scene: Option<Scene>,
+    pub title: String,
+    pub pose: Pose,
+    pub width: f32,
+}
+
+impl LoadingScreen {
+    pub fn new(manifest: &AssetManifest, next_scene: Option<Scene>) -> Self {
+        Self {
+            id: "LoadingScreen".to_string(),
+            sk_info: None,
+            manifest: manifest.clone(),
+            preload: None,
+            kept: None,
+            next_scene,
+            title: format!("Loading {}", manifest.name),
+            pose: Pose::new(Vec3::new(0.0, 1.5, -0.6), Some(Quat::from_angles(0.0, 180.0, 0.0))),
+            width: 30.0 * CM,
+        }
+    }
+
+    /// The progress of the preload, 0.0 before the initialization.
+    pub fn get_progress(&self) -> f32 {
+        match (&self.preload, &self.kept) {
+            (Some(preload), _) => preload.get_progress(),
+            (None, Some(_)) => 1.0,
+            _ => 0.0,
+        }
+    }
+
+    fn finish(&mut self, preload: AssetPreload) {
+        let errors = preload.get_errors();
+        for error in errors.iter() {
+            Log::warn(format!("{}: {}", self.id, error));
+        }
+        let Some(sk_info) = self.sk_info.as_ref() else { return };
+        let scheduler = sk_info.borrow().get_scheduler();
+        let preloaded =
+            AssetsPreloaded { name: preload.get_name().into(), count: preload.get_count(), errors: errors.len() };
+        scheduler.push_action(Schedule::next_frame(), StepperAction::typed_event(self.id.clone(), preloaded));
+        match self.next_scene.take() {
+            Some(mut scene) => {
+                scene.add(format!("{} assets", self.id), PreloadKeeper { _preload: preload });
+                let mut scene = Some(scene);
+                scheduler.run(Schedule::next_frame(), move |sk| {
+                    if let Some(scene) = scene.take() {
+                        sk.replace_scene(scene)
+                    }
+                });
+            }
+            None => self.kept = Some(preload),
+        }
+    }
+}
+
+impl IStepper for LoadingScreen {
+    fn enabled(&self) -> bool {
+        self.kept.is_none()
+    }
+
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
+        self.id = id;
+        self.sk_info = Some(sk_info);
+        self.preload = Some(self.manifest.preload());
+        Ok(())
+    }
+
+    fn order(&self) -> StepperOrder {
+        StepperOrder::new(StepperPhase::Ui)
+    }
+
+    fn step(&mut self, _token: &MainThreadToken) {
+        let Some(preload) = self.preload.as_ref() else { return };
+        if preload.is_finished() {
+            if let Some(preload) = self.preload.take() {
+                self.finish(preload);
+            }
+            return;
+        }
+
+        Ui::window_begin(&self.title, &mut self.pose, Some(Vec2::new(self.width, 0.0)), None, None);
+        Ui::label(format!("{} / {} assets", preload.get_finished_count(), preload.get_count()), None, true);
+        Ui::progress_bar(preload.get_progress(), 0.0);
+        Ui::window_end();
+    }
+}
+
+/// Keeps the preloaded assets alive as long as the scene it belongs to.
+struct PreloadKeeper {
+    _preload: AssetPreload,
+}
+
+impl IStepper for PreloadKeeper {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn initialize(&mut self, _id: StepperId, _sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
+        Ok(())
+    }
+
+    fn step(&mut self, _token: &MainThreadToken) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_from_toml() {
+        let manifest = AssetManifest::from_toml(
+            r#"
+            name = "Demo assets"
+
+            [[asset]]
+            path = "textures/parquet2/parquet2.ktx2"
+            type = "tex"
+            id = "parquet"
+            priority = 5
+            srgb = false
+
+            [[asset]]
+            path = "plane.glb"
+            type = "model"
+
+            [[asset]]
+            path = "sounds/click.wav"
+            type = "sound"
+            priority = -1
+            "#,
+        )
+        .unwrap();
+        let mut expected = AssetManifest::new("Demo assets");
+        expected
+            .add(ManifestEntry::tex("textures/parquet2/parquet2.ktx2").id("parquet").priority(5).srgb(false))
+            .add(ManifestEntry::model("plane.glb"))
+            .add(ManifestEntry::sound("sounds/click.wav").priority(-1));
+        assert_eq!(manifest, expected);
+        assert_eq!(AssetManifest::from_toml("").unwrap(), AssetManifest::new("assets"));
+    }
+
+    #[test]
+    fn manifest_errors() {
+        for text in [
+            "name = ",
+            "asset = 1",
+            "[[asset]]\ntype = \"tex\"",
+            "[[asset]]\npath = \"a.png\"",
+            "[[asset]]\npath = \"a.png\"\ntype = \"texture\"",
+            "[[asset]]\npath = \"a.png\"\ntype = \"tex\"\npriority = 3000000000",
+        ] {
+            assert!(matches!(AssetManifest::from_toml(text), Err(StereoKitError::AssetManifest(..))), "{}", text);
+        }
+    }
+}
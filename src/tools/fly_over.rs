@@ -1,11 +1,12 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    event_loop::{IStepper, StepperId},
+    event_loop::{IStepper, StepperId, StepperOrder, StepperParams, StepperPhase},
     maths::{Matrix, Quat, Vec2, Vec3},
     sk::{MainThreadToken, SkInfo},
     system::{Handed, Input, Renderer},
     util::Time,
+    StereoKitError,
 };
 
 pub struct FlyOver {
@@ -15,8 +16,6 @@ pub struct FlyOver {
     pub rotate_speed: f32,
 }
 
-unsafe impl Send for FlyOver {}
-
 impl Default for FlyOver {
     fn default() -> Self {
         Self { id: "FlyOver".to_string(), sk_info: None, move_speed: 2.0, rotate_speed: 90.0 }
@@ -24,15 +23,30 @@ impl Default for FlyOver {
 }
 
 impl IStepper for FlyOver {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
-        true
+        Ok(())
+    }
+
+    fn order(&self) -> StepperOrder {
+        StepperOrder::new(StepperPhase::Input)
     }
 
     fn step(&mut self, token: &MainThreadToken) {
         self.draw(token)
     }
+
+    fn save_state(&self) -> Option<StepperParams> {
+        let mut state = StepperParams::new();
+        state.set("move_speed", self.move_speed).set("rotate_speed", self.rotate_speed);
+        Some(state)
+    }
+
+    fn load_state(&mut self, state: &StepperParams) {
+        self.move_speed = state.get_or("move_speed", self.move_speed);
+        self.rotate_speed = state.get_or("rotate_speed", self.rotate_speed);
+    }
 }
 
 impl FlyOver {
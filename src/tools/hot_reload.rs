@@ -0,0 +1,300 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    ffi::CStr,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    event_loop::{IStepper, StepperId},
+    model::Model,
+    shader::Shader,
+    sk::{MainThreadToken, SkInfo},
+    sound::Sound,
+    sprite::Sprite,
+    system::{Asset, AssetType, Assets, Log},
+    tex::{Tex, TexFormat},
+    StereoKitError,
+};
+
+/// Watch the files of SkSettings::assets_folder and reload the Tex, Model, Shader, Sound and Sprite assets whose id
+/// is the path of a modified file. Only available in debug builds on Linux.
+/// * Tex are reloaded in place, so the materials using them show the new data right away.
+/// * Model nodes get the meshes and materials of the new file (matched by node name). The shaders of the previous
+///   materials are kept.
+/// * Shader are replaced in all the materials using them.
+/// * Sound and Sprite are re-created with the same id, so the next find gets the new data, the handles already kept
+///   by the steppers still use the previous data.
+///
+/// ```ignore
+/// #[cfg(all(debug_assertions, target_os = "linux"))]
+/// sk.add_stepper_default::<HotReload>("HotReload");
+/// ```
+pub struct HotReload {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    receiver: Option<mpsc::Receiver<PathBuf>>,
+    /// The re-created assets, kept here until they are reloaded again.
+    reloaded: HashMap<String, Asset>,
+    running: Arc<AtomicBool>,
+    /// The watched assets folder.
+    root: PathBuf,
+    /// The delay between two scans of the assets folder.
+    pub interval: Duration,
+}
+
+impl Default for HotReload {
+    fn default() -> Self {
+        Self {
+            id: "HotReload".to_string(),
+            sk_info: None,
+            receiver: None,
+            reloaded: HashMap::new(),
+            running: Arc::new(AtomicBool::new(false)),
+            root: PathBuf::from("."),
+            interval: Duration::from_millis(500),
+        }
+    }
+}
+
+impl IStepper for HotReload {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
+        self.id = id;
+        let settings = sk_info.borrow().get_settings();
+        self.sk_info = Some(sk_info);
+        let assets_folder = match settings.assets_folder.is_null() {
+            true => "",
+            false => unsafe { CStr::from_ptr(settings.assets_folder) }.to_str().unwrap_or(""),
+        };
+        let root = if assets_folder.is_empty() { PathBuf::from(".") } else { PathBuf::from(assets_folder) };
+        self.root = root.clone();
+        if !root.is_dir() {
+            Log::warn(format!("{}: assets folder {:?} not found, nothing to watch", self.id, root));
+            return Ok(());
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        self.receiver = Some(receiver);
+        self.running.store(true, Ordering::Relaxed);
+        let running = self.running.clone();
+        let interval = self.interval;
+        if let Err(error) =
+            thread::Builder::new().name(self.id.clone()).spawn(move || watch(root, interval, running, sender))
+        {
+            Log::warn(format!("{}: unable to start the watcher: {}", self.id, error));
+        }
+        Ok(())
+    }
+
+    fn step(&mut self, _token: &MainThreadToken) {
+        let Some(receiver) = self.receiver.as_ref() else { return };
+        let changed: Vec<PathBuf> = receiver.try_iter().collect();
+        for path in changed {
+            self.reload(&path);
+        }
+    }
+
+    fn shutdown(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        self.receiver = None;
+        self.reloaded.clear();
+    }
+}
+
+/// The watcher thread stops even if the stepper is dropped without being shut down.
+impl Drop for HotReload {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl HotReload {
+    /// Reload all the assets whose id is the path of this file.
+    fn reload(&mut self, path: &Path) {
+        let Some(extension) = path.extension().and_then(|e| e.to_str()) else { return };
+        let extension = format!(".{}", extension.to_lowercase());
+        let asset_types: &[AssetType] = if Assets::TEXTURE_FORMATS.contains(&extension.as_str()) {
+            &[AssetType::Tex, AssetType::Sprite]
+        } else if Assets::MODEL_FORMATS.contains(&extension.as_str()) {
+            &[AssetType::Model]
+        } else if Assets::SOUND_FORMATS.contains(&extension.as_str()) {
+            &[AssetType::Sound]
+        } else if extension == ".sks" {
+            &[AssetType::Shader]
+        } else {
+            return;
+        };
+
+        // StereoKit gives the file name used to load an asset as its id, relative to the assets folder
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        let is_file_of = |id: &str| !id.is_empty() && (Path::new(id) == relative || Path::new(id) == path);
+
+        for asset_type in asset_types {
+            // collected first as the reload creates new assets of the same type
+            let assets: Vec<Asset> = Assets::all_of_type(*asset_type).collect();
+            for asset in assets {
+                let result = match asset {
+                    Asset::Tex(mut tex) if is_file_of(tex.get_id()) => reload_tex(&mut tex, path),
+                    Asset::Model(mut model) if is_file_of(model.get_id()) => reload_model(&mut model, path),
+                    Asset::Shader(mut shader) if is_file_of(shader.get_id()) => {
+                        reload_shader(&mut shader, path).map(|new| keep(&mut self.reloaded, Asset::Shader(new)))
+                    }
+                    Asset::Sound(mut sound) if is_file_of(sound.get_id()) => {
+                        reload_sound(&mut sound, path).map(|new| keep(&mut self.reloaded, Asset::Sound(new)))
+                    }
+                    Asset::Sprite(mut sprite) if is_file_of(sprite.get_id()) => {
+                        reload_sprite(&mut sprite, path).map(|new| keep(&mut self.reloaded, Asset::Sprite(new)))
+                    }
+                    _ => continue,
+                };
+                match result {
+                    Ok(id) => Log::info(format!("{}: {:?} reloaded from {:?}", self.id, id, path)),
+                    Err(error) => Log::warn(format!("{}: {:?} not reloaded: {}", self.id, path, error)),
+                }
+            }
+        }
+    }
+}
+
+/// Scan the folder until `running` is false, sending the files modified since the previous scan once their
+/// modification time is stable (so files still being written are not sent).
+fn watch(root: PathBuf, interval: Duration, running: Arc<AtomicBool>, sender: mpsc::Sender<PathBuf>) {
+    let mut known = HashMap::new();
+    scan(&root, &mut known);
+    let mut pending: HashMap<PathBuf, SystemTime> = HashMap::new();
+    while running.load(Ordering::Relaxed) {
+        thread::sleep(interval);
+        let mut current = HashMap::new();
+        scan(&root, &mut current);
+        for (path, modified) in pending.drain() {
+            if current.get(&path) == Some(&modified) && sender.send(path).is_err() {
+                return;
+            }
+        }
+        for (path, modified) in current.iter() {
+            if known.get(path) != Some(modified) {
+                pending.insert(path.clone(), *modified);
+            }
+        }
+        known = current;
+    }
+}
+
+fn scan(dir: &Path, files: &mut HashMap<PathBuf, SystemTime>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else { continue };
+        if metadata.is_dir() {
+            scan(&path, files);
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path, modified);
+        }
+    }
+}
+
+/// Keep a re-created asset alive (releasing the one it replaced if it was also re-created), returns its id.
+fn keep(reloaded: &mut HashMap<String, Asset>, asset: Asset) -> String {
+    let id = match &asset {
+        Asset::Shader(shader) => shader.get_id().to_string(),
+        Asset::Sound(sound) => sound.get_id().to_string(),
+        Asset::Sprite(sprite) => sprite.get_id().to_string(),
+        _ => String::new(),
+    };
+    reloaded.insert(id.clone(), asset);
+    id
+}
+
+fn reload_tex(tex: &mut Tex, path: &Path) -> Result<String, StereoKitError> {
+    let data = fs::read(path).map_err(|e| StereoKitError::AssetLoad(e.to_string(), path.to_path_buf()))?;
+    let srgb = !matches!(tex.get_format(), Some(TexFormat::RGBA32Linear | TexFormat::BGRA32Linear));
+    tex.set_memory(&data, srgb, 0, 10);
+    Ok(tex.get_id().to_string())
+}
+
+fn reload_model(model: &mut Model, path: &Path) -> Result<String, StereoKitError> {
+    // so StereoKit loads the file instead of returning the live model
+    let id = model.get_id().to_string();
+    model.id(format!("{} (replaced)", id));
+    let new_model = Model::from_file(path, None);
+    model.id(&id);
+    let mut new_model = new_model?;
+    new_model.id(format!("{} (reloaded)", id));
+    let nodes = model.get_nodes();
+    for new_node in new_model.get_nodes().visuals() {
+        let Some(name) = new_node.get_name() else { continue };
+        let Some(mut node) = nodes.find(name) else { continue };
+        if let Some(mesh) = new_node.get_mesh() {
+            node.mesh(mesh);
+        }
+        if let Some(mut material) = new_node.get_material() {
+            if let Some(previous) = node.get_material() {
+                material.shader(previous.get_shader());
+            }
+            node.material(material);
+        }
+        node.local_transform(new_node.get_local_transform());
+    }
+    model.recalculate_bounds();
+    Ok(model.get_id().to_string())
+}
+
+fn reload_shader(shader: &mut Shader, path: &Path) -> Result<Shader, StereoKitError> {
+    let id = shader.get_id().to_string();
+    shader.id(format!("{} (replaced)", id));
+    let mut new_shader = match Shader::from_file(path) {
+        Ok(new_shader) => new_shader,
+        Err(error) => {
+            shader.id(&id);
+            return Err(error);
+        }
+    };
+    new_shader.id(&id);
+    let replaced = shader.get_id().to_string();
+    for asset in Assets::all_of_type(AssetType::Material) {
+        if let Asset::Material(mut material) = asset {
+            if material.get_shader().get_id() == replaced {
+                material.shader(&new_shader);
+            }
+        }
+    }
+    Ok(new_shader)
+}
+
+fn reload_sound(sound: &mut Sound, path: &Path) -> Result<Sound, StereoKitError> {
+    let id = sound.get_id().to_string();
+    sound.id(format!("{} (replaced)", id));
+    match Sound::from_file(path) {
+        Ok(mut new_sound) => {
+            new_sound.id(&id);
+            Ok(new_sound)
+        }
+        Err(error) => {
+            sound.id(&id);
+            Err(error)
+        }
+    }
+}
+
+fn reload_sprite(sprite: &mut Sprite, path: &Path) -> Result<Sprite, StereoKitError> {
+    let id = sprite.get_id().to_string();
+    sprite.id(format!("{} (replaced)", id));
+    match Sprite::from_file(path, None, None) {
+        Ok(mut new_sprite) => {
+            new_sprite.id(&id);
+            Ok(new_sprite)
+        }
+        Err(error) => {
+            sprite.id(&id);
+            Err(error)
+        }
+    }
+}
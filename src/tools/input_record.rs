@@ -0,0 +1,667 @@
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use crate::{
+    event_loop::{IStepper, Schedule, StepperAction, StepperId, StepperOrder, StepperPhase},
+    maths::{Pose, Quat, Vec2, Vec3},
+    sk::{MainThreadToken, SkInfo},
+    system::{BtnState, Controller, HandJoint, Handed, Input, Key, Log, Mouse, Renderer, TrackState},
+    util::Time,
+    StereoKitError,
+};
+
+/// The typed event to start (true) or stop (false) an [`InputRecorder`]. The file is written when the recording stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordInput(pub bool);
+
+/// The typed event sent by an [`InputPlayer`] when the last frame of its recording has been replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputReplayEnded(pub PathBuf);
+
+const MAGIC: &[u8; 8] = b"SKINPUT1";
+
+/// The joints of a hand, in the order expected by [`Input::hand_override`].
+pub type HandJoints = [HandJoint; 25];
+
+/// Everything the recorder has seen of the inputs during one frame.
+#[derive(Debug, Clone)]
+pub struct InputFrame {
+    /// Time::get_total of the frame.
+    pub time: f64,
+    /// Time::get_step of the frame.
+    pub step: f64,
+    /// Input::get_head of the frame.
+    pub head: Pose,
+    /// The joints of the left and right hands, None if the hand was not tracked.
+    pub hands: [Option<HandJoints>; 2],
+    /// The left and right controllers.
+    pub controllers: [Controller; 2],
+    pub mouse: Mouse,
+    /// The keys pressed (true) or released (false) during the frame, mouse buttons included.
+    pub keys: Vec<(Key, bool)>,
+    /// The characters typed during the frame.
+    pub text: String,
+}
+
+impl InputFrame {
+    /// Capture the inputs of the current frame. If other steppers are reading Input::text_consume, the recorder must
+    /// be stepped before them.
+    pub fn capture() -> Self {
+        let mut hands = [None, None];
+        for (i, handed) in [Handed::Left, Handed::Right].into_iter().enumerate() {
+            let hand = Input::hand(handed);
+            if hand.tracked.is_active() {
+                let mut joints = [HandJoint { position: Vec3::ZERO, orientation: Quat::IDENTITY, radius: 0.0 }; 25];
+                for (finger, finger_joints) in hand.fingers.iter().enumerate() {
+                    joints[finger * 5..finger * 5 + 5].copy_from_slice(finger_joints);
+                }
+                hands[i] = Some(joints);
+            }
+        }
+
+        let mut keys = vec![];
+        for key in ALL_KEYS.iter().copied() {
+            let state = Input::key(key);
+            if state.is_just_active() {
+                keys.push((key, true));
+            }
+            if state.is_just_inactive() {
+                keys.push((key, false));
+            }
+        }
+
+        let mut text = String::new();
+        while let Some(character) = Input::text_consume() {
+            if character == '\0' {
+                break;
+            }
+            text.push(character);
+        }
+        Input::text_reset();
+
+        Self {
+            time: Time::get_total(),
+            step: Time::get_step(),
+            head: Input::get_head(),
+            hands,
+            controllers: [Input::controller(Handed::Left), Input::controller(Handed::Right)],
+            mouse: Input::get_mouse(),
+            keys,
+            text,
+        }
+    }
+
+    /// Apply the frame to StereoKit: time is pinned, hands are overridden, keys and text are injected and the camera
+    /// root is moved to the recorded head. Controllers and mouse can't be injected into StereoKit, read them from
+    /// [`InputPlayer::get_frame`]. Hands, keys and text will be seen by StereoKit at the next frame.
+    ///
+    /// The head is replayed through the camera root, this is exact when the local head stays at the origin as it does
+    /// with AppMode::Offscreen.
+    pub fn apply(&self) {
+        Time::set_time(self.time, self.step);
+        Renderer::camera_root(self.head.to_matrix(None));
+        for (handed, hand) in [Handed::Left, Handed::Right].into_iter().zip(self.hands.iter()) {
+            match hand {
+                Some(joints) => Input::hand_override(handed, joints),
+                None => Input::hand_clear_override(handed),
+            }
+        }
+        for (key, pressed) in &self.keys {
+            if *pressed {
+                Input::key_inject_press(*key)
+            } else {
+                Input::key_inject_release(*key)
+            }
+        }
+        if !self.text.is_empty() {
+            Input::text_inject_chars(&self.text);
+        }
+    }
+}
+
+/// A sequence of frames that can be saved to and loaded from a file.
+#[derive(Debug, Clone, Default)]
+pub struct InputRecording {
+    pub frames: Vec<InputFrame>,
+}
+
+impl InputRecording {
+    /// Write the recording to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), StereoKitError> {
+        let path = path.as_ref();
+        let mut out = Vec::with_capacity(MAGIC.len() + self.frames.len() * 1024);
+        out.extend_from_slice(MAGIC);
+        put_u32(&mut out, self.frames.len() as u32);
+        for frame in &self.frames {
+            write_frame(&mut out, frame);
+        }
+        fs::write(path, out).map_err(|e| StereoKitError::InputRecord(path.to_path_buf(), e.to_string()))
+    }
+
+    /// Read a recording previously written by [`InputRecording::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StereoKitError> {
+        let path = path.as_ref();
+        let data = fs::read(path).map_err(|e| StereoKitError::InputRecord(path.to_path_buf(), e.to_string()))?;
+        let mut reader = Reader { data: &data };
+        let frames = (|| {
+            if reader.take(MAGIC.len())? != MAGIC {
+                return None;
+            }
+            let count = reader.u32()?;
+            let mut frames = Vec::with_capacity(count as usize);
+            for _ in 0..count {
+                frames.push(read_frame(&mut reader)?);
+            }
+            Some(frames)
+        })();
+        match frames {
+            Some(frames) => Ok(Self { frames }),
+            None => Err(StereoKitError::InputRecord(path.to_path_buf(), "truncated or not an input recording".into())),
+        }
+    }
+}
+
+/// Record the inputs while enabled then write them to `path` when disabled or shut down. Use the typed event
+/// [`RecordInput`] or [`InputRecorder::record`] to start and stop.
+pub struct InputRecorder {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    enabled: bool,
+    pub path: PathBuf,
+    recording: InputRecording,
+}
+
+impl InputRecorder {
+    /// A recorder writing to `path`, started if `enabled`.
+    pub fn new(path: impl AsRef<Path>, enabled: bool) -> Self {
+        Self {
+            id: "InputRecorder".to_string(),
+            sk_info: None,
+            enabled,
+            path: path.as_ref().to_path_buf(),
+            recording: InputRecording::default(),
+        }
+    }
+
+    /// Start or stop the recording. Stopping writes the file.
+    pub fn record(&mut self, enabled: bool) {
+        if self.enabled && !enabled {
+            self.save();
+        } else if !self.enabled && enabled {
+            self.recording.frames.clear();
+        }
+        self.enabled = enabled;
+    }
+
+    fn save(&mut self) {
+        match self.recording.save(&self.path) {
+            Ok(()) => {
+                Log::info(format!("InputRecorder : {} frames written to {:?}", self.recording.frames.len(), self.path))
+            }
+            Err(error) => Log::err(format!("InputRecorder : {}", error)),
+        }
+    }
+}
+
+impl IStepper for InputRecorder {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
+        self.id = id;
+        self.sk_info = Some(sk_info);
+        Ok(())
+    }
+
+    fn order(&self) -> StepperOrder {
+        // First one, so the text is read before any other stepper consumes it
+        StepperOrder::new(StepperPhase::Input).priority(i32::MIN)
+    }
+
+    fn step(&mut self, token: &MainThreadToken) {
+        for (_, RecordInput(record)) in token.get_events::<RecordInput>() {
+            self.record(*record);
+        }
+        if self.enabled {
+            self.recording.frames.push(InputFrame::capture());
+        }
+    }
+
+    fn shutdown(&mut self) {
+        if self.enabled {
+            self.save();
+        }
+    }
+}
+
+/// Replay a file written by an [`InputRecorder`], one recorded frame per frame. When done, the hand overrides are
+/// cleared, [`InputReplayEnded`] is sent and the app quits if `quit_at_end` is set. Useful to replay a session headless
+/// with AppMode::Offscreen.
+pub struct InputPlayer {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    pub path: PathBuf,
+    pub quit_at_end: bool,
+    recording: InputRecording,
+    index: usize,
+}
+
+impl InputPlayer {
+    /// A player for the file at `path`. The file is loaded when the stepper is initialized.
+    pub fn new(path: impl AsRef<Path>, quit_at_end: bool) -> Self {
+        Self {
+            id: "InputPlayer".to_string(),
+            sk_info: None,
+            path: path.as_ref().to_path_buf(),
+            quit_at_end,
+            recording: InputRecording::default(),
+            index: 0,
+        }
+    }
+
+    /// The frame replayed during this step, to read the recorded controllers and mouse.
+    pub fn get_frame(&self) -> Option<&InputFrame> {
+        self.index.checked_sub(1).and_then(|i| self.recording.frames.get(i))
+    }
+
+    /// Is there still some frames to replay?
+    pub fn is_playing(&self) -> bool {
+        self.index < self.recording.frames.len()
+    }
+
+    fn end(&mut self) {
+        for handed in [Handed::Left, Handed::Right] {
+            Input::hand_clear_override(handed);
+        }
+        // release what is still pressed at the end of the recording
+        let mut pressed: Vec<Key> = vec![];
+        for (key, is_press) in self.recording.frames.iter().flat_map(|frame| frame.keys.iter()) {
+            pressed.retain(|k| k != key);
+            if *is_press {
+                pressed.push(*key);
+            }
+        }
+        for key in pressed {
+            Input::key_inject_release(key);
+        }
+
+        let Some(sk_info) = self.sk_info.as_ref() else { return };
+        let scheduler = sk_info.borrow().get_scheduler();
+        let ended = StepperAction::typed_event(self.id.clone(), InputReplayEnded(self.path.clone()));
+        scheduler.push_action(Schedule::next_frame(), ended);
+        if self.quit_at_end {
+            let quit = StepperAction::Quit(self.id.clone(), "end of input replay".into());
+            scheduler.push_action(Schedule::next_frame(), quit);
+        }
+    }
+}
+
+impl IStepper for InputPlayer {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
+        self.id = id;
+        self.sk_info = Some(sk_info);
+        self.recording = InputRecording::load(&self.path)
+            .map_err(|error| StereoKitError::StepperInit(self.id.clone(), error.to_string()))?;
+        self.index = 0;
+        Ok(())
+    }
+
+    fn order(&self) -> StepperOrder {
+        // First one, so the other steppers see the recorded time
+        StepperOrder::new(StepperPhase::Input).priority(i32::MIN)
+    }
+
+    fn step(&mut self, _token: &MainThreadToken) {
+        if let Some(frame) = self.recording.frames.get(self.index) {
+            frame.apply();
+            self.index += 1;
+            if !self.is_playing() {
+                self.end();
+            }
+        }
+    }
+
+    fn shutdown(&mut self) {
+        for handed in [Handed::Left, Handed::Right] {
+            Input::hand_clear_override(handed);
+        }
+    }
+}
+
+const ALL_KEYS: &[Key] = &[
+    Key::MouseLeft,
+    Key::MouseRight,
+    Key::MouseCenter,
+    Key::MouseForward,
+    Key::MouseBack,
+    Key::Backspace,
+    Key::Tab,
+    Key::Return,
+    Key::Shift,
+    Key::Ctrl,
+    Key::Alt,
+    Key::CapsLock,
+    Key::Esc,
+    Key::Space,
+    Key::End,
+    Key::Home,
+    Key::Left,
+    Key::Right,
+    Key::Up,
+    Key::Down,
+    Key::PageUp,
+    Key::PageDown,
+    Key::PrintScreen,
+    Key::KeyInsert,
+    Key::Del,
+    Key::Key0,
+    Key::Key1,
+    Key::Key2,
+    Key::Key3,
+    Key::Key4,
+    Key::Key5,
+    Key::Key6,
+    Key::Key7,
+    Key::Key8,
+    Key::Key9,
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+    Key::Numpad0,
+    Key::Numpad1,
+    Key::Numpad2,
+    Key::Numpad3,
+    Key::Numpad4,
+    Key::Numpad5,
+    Key::Numpad6,
+    Key::Numpad7,
+    Key::Numpad8,
+    Key::Numpad9,
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::Comma,
+    Key::Period,
+    Key::SlashFwd,
+    Key::SlashBack,
+    Key::Semicolon,
+    Key::Apostrophe,
+    Key::BracketOpen,
+    Key::BracketClose,
+    Key::Minus,
+    Key::Equals,
+    Key::Backtick,
+    Key::LCmd,
+    Key::RCmd,
+    Key::Multiply,
+    Key::Add,
+    Key::Subtract,
+    Key::Decimal,
+    Key::Divide,
+];
+
+fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_f32(out: &mut Vec<u8>, value: f32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_f64(out: &mut Vec<u8>, value: f64) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_vec3(out: &mut Vec<u8>, value: Vec3) {
+    for v in [value.x, value.y, value.z] {
+        put_f32(out, v);
+    }
+}
+
+fn put_pose(out: &mut Vec<u8>, pose: &Pose) {
+    put_vec3(out, pose.position);
+    let q = pose.orientation;
+    for v in [q.x, q.y, q.z, q.w] {
+        put_f32(out, v);
+    }
+}
+
+fn write_frame(out: &mut Vec<u8>, frame: &InputFrame) {
+    put_f64(out, frame.time);
+    put_f64(out, frame.step);
+    put_pose(out, &frame.head);
+    for hand in &frame.hands {
+        match hand {
+            Some(joints) => {
+                out.push(1);
+                for joint in joints {
+                    put_pose(out, &Pose::new(joint.position, Some(joint.orientation)));
+                    put_f32(out, joint.radius);
+                }
+            }
+            None => out.push(0),
+        }
+    }
+    for controller in &frame.controllers {
+        put_pose(out, &controller.pose);
+        put_pose(out, &controller.palm);
+        put_pose(out, &controller.aim);
+        put_u32(out, controller.tracked.bits());
+        put_u32(out, controller.tracked_pos as u32);
+        put_u32(out, controller.tracked_rot as u32);
+        put_u32(out, controller.stick_click.bits());
+        put_u32(out, controller.x1.bits());
+        put_u32(out, controller.x2.bits());
+        put_f32(out, controller.trigger);
+        put_f32(out, controller.grip);
+        put_f32(out, controller.stick.x);
+        put_f32(out, controller.stick.y);
+    }
+    let mouse = &frame.mouse;
+    put_u32(out, mouse.available as u32);
+    for v in [mouse.pos.x, mouse.pos.y, mouse.pos_change.x, mouse.pos_change.y, mouse.scroll, mouse.scroll_change] {
+        put_f32(out, v);
+    }
+    put_u32(out, frame.keys.len() as u32);
+    for (key, pressed) in &frame.keys {
+        put_u32(out, *key as u32);
+        out.push(*pressed as u8);
+    }
+    put_u32(out, frame.text.len() as u32);
+    out.extend_from_slice(frame.text.as_bytes());
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.data.len() < len {
+            return None;
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        Some(f32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        Some(f64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    fn vec3(&mut self) -> Option<Vec3> {
+        Some(Vec3::new(self.f32()?, self.f32()?, self.f32()?))
+    }
+
+    fn pose(&mut self) -> Option<Pose> {
+        let position = self.vec3()?;
+        let orientation = Quat::new(self.f32()?, self.f32()?, self.f32()?, self.f32()?);
+        Some(Pose::new(position, Some(orientation)))
+    }
+
+    fn btn_state(&mut self) -> Option<BtnState> {
+        Some(BtnState::from_bits_truncate(self.u32()?))
+    }
+
+    fn track_state(&mut self) -> Option<TrackState> {
+        Some(match self.u32()? {
+            0 => TrackState::Lost,
+            1 => TrackState::Inferred,
+            _ => TrackState::Known,
+        })
+    }
+}
+
+fn read_frame(reader: &mut Reader) -> Option<InputFrame> {
+    let time = reader.f64()?;
+    let step = reader.f64()?;
+    let head = reader.pose()?;
+    let mut hands = [None, None];
+    for hand in hands.iter_mut() {
+        if reader.u8()? != 0 {
+            let mut joints = [HandJoint { position: Vec3::ZERO, orientation: Quat::IDENTITY, radius: 0.0 }; 25];
+            for joint in joints.iter_mut() {
+                let pose = reader.pose()?;
+                *joint = HandJoint { position: pose.position, orientation: pose.orientation, radius: reader.f32()? };
+            }
+            *hand = Some(joints);
+        }
+    }
+    let mut read_controller = || -> Option<Controller> {
+        Some(Controller {
+            pose: reader.pose()?,
+            palm: reader.pose()?,
+            aim: reader.pose()?,
+            tracked: reader.btn_state()?,
+            tracked_pos: reader.track_state()?,
+            tracked_rot: reader.track_state()?,
+            stick_click: reader.btn_state()?,
+            x1: reader.btn_state()?,
+            x2: reader.btn_state()?,
+            trigger: reader.f32()?,
+            grip: reader.f32()?,
+            stick: Vec2::new(reader.f32()?, reader.f32()?),
+        })
+    };
+    let controllers = [read_controller()?, read_controller()?];
+    let mouse = Mouse {
+        available: reader.u32()? as i32,
+        pos: Vec2::new(reader.f32()?, reader.f32()?),
+        pos_change: Vec2::new(reader.f32()?, reader.f32()?),
+        scroll: reader.f32()?,
+        scroll_change: reader.f32()?,
+    };
+    let key_count = reader.u32()?;
+    let mut keys = vec![];
+    for _ in 0..key_count {
+        let code = reader.u32()?;
+        let pressed = reader.u8()? != 0;
+        if let Some(key) = ALL_KEYS.iter().find(|key| **key as u32 == code) {
+            keys.push((*key, pressed));
+        }
+    }
+    let text_len = reader.u32()? as usize;
+    let text = String::from_utf8_lossy(reader.take(text_len)?).to_string();
+    Some(InputFrame { time, step, head, hands, controllers, mouse, keys, text })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(time: f64, with_hand: bool) -> InputFrame {
+        let pose = Pose::new(Vec3::new(0.1, 1.6, -0.3), Some(Quat::new(0.0, 0.6, 0.0, 0.8)));
+        let joint = HandJoint { position: Vec3::new(0.2, 1.2, -0.4), orientation: Quat::IDENTITY, radius: 0.01 };
+        let controller = Controller {
+            pose,
+            palm: pose,
+            aim: Pose::IDENTITY,
+            tracked: BtnState::Active,
+            tracked_pos: TrackState::Known,
+            tracked_rot: TrackState::Inferred,
+            stick_click: BtnState::JustActive | BtnState::Active,
+            x1: BtnState::Inactive,
+            x2: BtnState::JustInactive,
+            trigger: 0.5,
+            grip: 1.0,
+            stick: Vec2::new(-0.25, 0.75),
+        };
+        let mouse = Mouse {
+            available: 1,
+            pos: Vec2::new(640.0, 360.0),
+            pos_change: Vec2::new(2.0, -1.0),
+            scroll: 120.0,
+            scroll_change: 0.0,
+        };
+        InputFrame {
+            time,
+            step: 1.0 / 72.0,
+            head: pose,
+            hands: [if with_hand { Some([joint; 25]) } else { None }, None],
+            controllers: [controller, controller],
+            mouse,
+            keys: vec![(Key::A, true), (Key::Shift, false)],
+            text: "aé".into(),
+        }
+    }
+
+    #[test]
+    fn recording_roundtrip() {
+        let recording = InputRecording { frames: vec![frame(1.0, true), frame(1.0 + 1.0 / 72.0, false)] };
+        let path = std::env::temp_dir().join(format!("input_record_roundtrip_{}.skinput", std::process::id()));
+        recording.save(&path).unwrap();
+        let loaded = InputRecording::load(&path);
+        let data = fs::read(&path).unwrap();
+        fs::write(&path, &data[..data.len() - 1]).unwrap();
+        let truncated = InputRecording::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(format!("{:?}", loaded.unwrap().frames), format!("{:?}", recording.frames));
+        assert!(truncated.is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use std::{cell::RefCell, rc::Rc, sync::Mutex};
 
 use crate::{
-    event_loop::{IStepper, StepperAction, StepperId},
+    event_loop::{IStepper, StepperId, StepperOrder, StepperParams, StepperPhase, StringEvent},
     font::Font,
     material::Cull,
     maths::{units::CM, Matrix, Pose, Vec2, Vec3},
@@ -9,6 +9,7 @@ use crate::{
     system::{LogLevel, Text, TextAlign, TextFit, TextStyle},
     ui::{Ui, UiCut},
     util::Color128,
+    StereoKitError,
 };
 
 #[derive(Debug, Clone)]
@@ -18,8 +19,13 @@ pub struct LogItem {
     pub count: i32,
 }
 
+/// The string event key to show/hide the log window, prefer the typed event [`ShowLogWindow`].
 pub const SHOW_LOG_WINDOW: &str = "ShowLogWindow";
 
+/// The typed event to show/hide the log window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowLogWindow(pub bool);
+
 pub struct LogWindow<'a> {
     id: StepperId,
     sk_info: Option<Rc<RefCell<SkInfo>>>,
@@ -36,33 +42,46 @@ pub struct LogWindow<'a> {
     items_size: usize,
 }
 
-unsafe impl<'a> Send for LogWindow<'a> {}
-
-impl<'a> IStepper for LogWindow<'a> {
+impl IStepper for LogWindow<'static> {
     fn enabled(&self) -> bool {
         self.enabled
     }
 
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
 
-        true
+        Ok(())
+    }
+
+    fn order(&self) -> StepperOrder {
+        StepperOrder::new(StepperPhase::Ui)
     }
 
     fn step(&mut self, token: &MainThreadToken) {
-        for e in token.get_event_report().iter() {
-            if let StepperAction::Event(_, key, value) = e {
-                if key.eq(SHOW_LOG_WINDOW) {
-                    self.enabled = value.parse().unwrap_or(false)
-                }
-            }
+        for (_, ShowLogWindow(show)) in token.get_events::<ShowLogWindow>() {
+            self.enabled = *show
+        }
+        for (_, event) in token.get_events::<StringEvent>().filter(|(_, e)| e.key.eq(SHOW_LOG_WINDOW)) {
+            self.enabled = event.get_bool().unwrap_or(false)
         }
 
         self.draw(token)
     }
 
     fn shutdown(&mut self) {}
+
+    fn save_state(&self) -> Option<StepperParams> {
+        let mut state = StepperParams::new();
+        state.set_pose("pose", self.pose);
+        Some(state)
+    }
+
+    fn load_state(&mut self, state: &StepperParams) {
+        if let Some(pose) = state.get_pose("pose") {
+            self.pose = pose;
+        }
+    }
 }
 
 impl<'a> LogWindow<'a> {
@@ -1,7 +1,15 @@
+pub mod asset_browser;
+pub mod asset_cache;
+pub mod asset_leaks;
+pub mod asset_manifest;
 pub mod fly_over;
+#[cfg(all(debug_assertions, target_os = "linux"))]
+pub mod hot_reload;
+pub mod input_record;
 pub mod log_window;
 pub mod notif;
 pub mod os_api;
 pub mod passthrough_fb_ext;
+pub mod profiler_window;
 pub mod screenshoot;
 pub mod virtual_kbd_meta;
@@ -1,18 +1,21 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    event_loop::{IStepper, StepperAction, StepperId},
+    event_loop::{IStepper, Schedule, StepperAction, StepperId, TaskHandle},
     font::Font,
     maths::{Matrix, Quat, Vec3},
     shader::Shader,
     sk::{MainThreadToken, SkInfo},
     system::{Text, TextStyle},
-    util::{named_colors::BLACK, Time},
+    util::named_colors::BLACK,
+    StereoKitError,
 };
 
 pub struct HudNotification {
     id: StepperId,
     sk_info: Option<Rc<RefCell<SkInfo>>>,
+    /// The task removing this notification once its duration is elapsed.
+    remove_task: Option<TaskHandle>,
     pub text: String,
     pub duration: f32,
     pub position: Vec3,
@@ -20,8 +23,6 @@ pub struct HudNotification {
     pub text_style: TextStyle,
 }
 
-unsafe impl Send for HudNotification {}
-
 impl Default for HudNotification {
     fn default() -> Self {
         let hud_text_shader = Shader::from_file("shaders/hud_text.hlsl.sks").unwrap();
@@ -33,6 +34,7 @@ impl Default for HudNotification {
         Self {
             id: "HudNotification".to_string(),
             sk_info: None,
+            remove_task: None,
             text,
             duration: 5.0,
             position,
@@ -43,28 +45,31 @@ impl Default for HudNotification {
 }
 
 impl IStepper for HudNotification {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
         self.transform_text = Matrix::tr(&self.position, &Quat::from_angles(0.0, 180.0, 0.0));
-        true
+        let scheduler = self.sk_info.as_ref().unwrap().borrow().get_scheduler();
+        let remove_task =
+            scheduler.push_action(Schedule::after(self.duration as f64), StepperAction::remove(self.id.clone()));
+        self.remove_task = Some(remove_task);
+        Ok(())
     }
 
     fn step(&mut self, token: &MainThreadToken) {
         self.draw(token)
     }
+
+    fn shutdown(&mut self) {
+        // a notification added later with the same id must not be removed by this one's timer
+        if let Some(remove_task) = self.remove_task.take() {
+            remove_task.cancel();
+        }
+    }
 }
 
 impl HudNotification {
     fn draw(&mut self, token: &MainThreadToken) {
         Text::add_at(token, &self.text, self.transform_text, Some(self.text_style), None, None, None, None, None, None);
-
-        self.duration -= Time::get_stepf();
-        if self.duration < 0.0 {
-            let rc_sk = self.sk_info.as_ref().unwrap();
-            let sk = rc_sk.as_ref();
-            let event_loop_proxy = sk.borrow().get_event_loop_proxy().unwrap();
-            let _ = event_loop_proxy.send_event(StepperAction::Remove(self.id.clone()));
-        }
     }
 }
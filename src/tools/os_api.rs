@@ -3,6 +3,8 @@ use openxr_sys::{Result, Session};
 
 use crate::sk::SkInfo;
 use crate::system::{BackendOpenXR, Log};
+use crate::vfs::VfsEntry;
+use crate::StereoKitError;
 use std::ffi::OsString;
 use std::fs::File;
 use std::path::Path;
@@ -14,77 +16,29 @@ pub enum PathEntry {
     Dir(OsString),
 }
 
-/// Read all the assets of a given assets sub directory
-#[cfg(target_os = "android")]
+/// Read all the assets of a given assets sub directory, from the mounts of SkInfo::get_vfs (the assets folder on
+/// desktop, the assets of the APK on Android, plus the mounts added by the app).
+/// * file_extensions - with their dot (".png"), all the files if empty.
 pub fn get_assets(sk_info: Rc<RefCell<SkInfo>>, sub_dir: PathBuf, file_extensions: &Vec<String>) -> Vec<PathEntry> {
-    use std::ffi::CString;
-
-    let mut sk_i = sk_info.borrow_mut();
-    let app = sk_i.get_android_app();
+    let vfs = sk_info.borrow().get_vfs();
     let mut exts = vec![];
     for extension in file_extensions {
         let extension = extension[1..].to_string();
         exts.push(OsString::from(extension));
     }
     let mut vec = vec![];
-    if let Ok(cstring) = CString::new(sub_dir.to_str().unwrap_or("Error!!!")) {
-        if let Some(asset_dir) = app.asset_manager().open_dir(cstring.as_c_str()) {
-            for entry in asset_dir {
-                if let Ok(entry_string) = entry.into_string() {
-                    let path = PathBuf::from(entry_string.clone());
-
-                    if exts.is_empty() {
-                        if let Some(file_name) = path.file_name() {
-                            vec.push(PathEntry::File(file_name.into()))
-                        } else {
-                            Log::err(format!("get_assets, path {:?} don't have a file_name", path));
-                        }
-                    } else if let Some(extension) = path.extension() {
-                        if exts.contains(&extension.to_os_string()) {
-                            if let Some(file_name) = path.file_name() {
-                                vec.push(PathEntry::File(file_name.into()))
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-    vec
-}
-
-/// Read all the assets of a given assets sub directory
-#[cfg(not(target_os = "android"))]
-pub fn get_assets(_sk_info: Rc<RefCell<SkInfo>>, sub_dir: PathBuf, file_extensions: &Vec<String>) -> Vec<PathEntry> {
-    use std::{env, fs::read_dir};
-    let sub_dir = sub_dir.to_str().unwrap_or("");
-    let mut exts = vec![];
-    for extension in file_extensions {
-        let extension = extension[1..].to_string();
-        exts.push(OsString::from(extension));
-    }
-    let path_text = env::current_dir().unwrap().to_owned().join("assets");
-    let path_asset = path_text.join(sub_dir);
-    let mut vec = vec![];
-
-    if path_asset.exists() && path_asset.is_dir() {
-        if let Ok(read_dir) = read_dir(path_asset) {
-            for file in read_dir.flatten() {
-                let path = file.path();
-
-                if file.path().is_file() {
-                    if exts.is_empty() {
-                        vec.push(PathEntry::File(file.file_name()))
-                    } else if let Some(extension) = path.extension() {
-                        if exts.is_empty() || exts.contains(&extension.to_os_string()) {
-                            vec.push(PathEntry::File(file.file_name()))
-                        }
-                    }
+    for entry in vfs.read_dir(sub_dir) {
+        if let VfsEntry::File(file_name) = entry {
+            let path = PathBuf::from(file_name);
+            if exts.is_empty() {
+                vec.push(PathEntry::File(path.into_os_string()))
+            } else if let Some(extension) = path.extension() {
+                if exts.contains(&extension.to_os_string()) {
+                    vec.push(PathEntry::File(path.into_os_string()))
                 }
             }
         }
     }
-
     vec
 }
 
@@ -96,10 +50,27 @@ pub fn get_internal_path(sk_info: Rc<RefCell<SkInfo>>) -> Option<PathBuf> {
     app.internal_data_path()
 }
 
-/// Get the path to internal data directory for non android
+/// Get the path to internal data directory for non android: the config directory of the app named
+/// SkSettings::app_name, under $XDG_CONFIG_HOME or ~/.config on Linux and under %APPDATA% on Windows.
+/// The directory may not exist yet.
 #[cfg(not(target_os = "android"))]
-pub fn get_internal_path(_sk_info: Rc<RefCell<SkInfo>>) -> Option<PathBuf> {
-    None
+pub fn get_internal_path(sk_info: Rc<RefCell<SkInfo>>) -> Option<PathBuf> {
+    use std::ffi::CStr;
+    let app_name = sk_info.borrow().get_settings().app_name;
+    if app_name.is_null() {
+        return None;
+    }
+    let app_name = unsafe { CStr::from_ptr(app_name) }.to_string_lossy().replace(['/', '\\', ':'], "_");
+
+    #[cfg(target_os = "windows")]
+    let config_dir = std::env::var_os("APPDATA").map(PathBuf::from);
+    #[cfg(not(target_os = "windows"))]
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")));
+
+    config_dir.map(|dir| dir.join(app_name))
 }
 
 /// Get the path to external data directory for Android
@@ -119,42 +90,22 @@ pub fn get_external_path(_sk_info: Rc<RefCell<SkInfo>>) -> Option<PathBuf> {
     Some(path_assets)
 }
 
-/// Open an asset like a file
-#[cfg(target_os = "android")]
+/// Open an asset like a file, from the mounts of SkInfo::get_vfs. The assets that are not in a mounted directory
+/// (APK assets, zip archives, bundles) are extracted the first time they are opened, see [`crate::vfs::Vfs::get_file`].
 pub fn open_asset(sk_info: Rc<RefCell<SkInfo>>, asset_path: impl AsRef<Path>) -> Option<File> {
-    use std::ffi::CString;
-
-    let mut sk_i = sk_info.borrow_mut();
-    let app = sk_i.get_android_app();
-
-    if let Ok(cstring) = CString::new(asset_path.as_ref().to_str().unwrap_or("Error!!!")) {
-        if let Some(asset) = app.asset_manager().open(cstring.as_c_str()) {
-            if let Ok(o_file_desc) = asset.open_file_descriptor() {
-                Some(File::from(o_file_desc.fd))
-            } else {
-                Log::err(format!("open_asset, {:?} cannot get a new file_descriptor", asset_path.as_ref()));
-                None
-            }
-        } else {
-            Log::err(format!("open_asset, path {:?} cannot be a opened", asset_path.as_ref()));
+    let vfs = sk_info.borrow().get_vfs();
+    match vfs
+        .get_file(&asset_path)
+        .and_then(|path| File::open(path).map_err(|e| StereoKitError::ReadFileError(e.to_string())))
+    {
+        Ok(file) => Some(file),
+        Err(error) => {
+            Log::err(format!("open_asset, path {:?} cannot be opened: {}", asset_path.as_ref(), error));
             None
         }
-    } else {
-        Log::err(format!("open_asset, path {:?} cannot be a cstring", asset_path.as_ref()));
-        None
     }
 }
 
-/// Open an asset like a file
-#[cfg(not(target_os = "android"))]
-pub fn open_asset(_sk_info: Rc<RefCell<SkInfo>>, asset_path: impl AsRef<Path>) -> Option<File> {
-    use std::env;
-
-    let path_assets = env::current_dir().unwrap().join("assets");
-    let path_asset = path_assets.join(asset_path);
-    File::open(path_asset).ok()
-}
-
 /// Read the files and eventually the sub directory of a given directory
 pub fn get_files(
     _sk_info: Rc<RefCell<SkInfo>>,
@@ -9,10 +9,11 @@ use openxr_sys::{
 };
 
 use crate::{
-    event_loop::{IStepper, StepperAction, StepperId},
+    event_loop::{IStepper, StepperId, StringEvent},
     sk::{MainThreadToken, SkInfo},
     system::{Backend, BackendOpenXR, BackendXRType, Log, Renderer},
     util::Color128,
+    StereoKitError,
 };
 use std::{cell::RefCell, ptr::null_mut, rc::Rc};
 
@@ -23,8 +24,13 @@ use std::{cell::RefCell, ptr::null_mut, rc::Rc};
 ///
 
 /// The StepperAction to trigger with the value "0"/"1" to Deactivate/Activate the passthrough.
+/// Prefer the typed event [`PassthroughFlip`].
 pub const PASSTHROUGH_FLIP: &str = "PassthroughFlip";
 
+/// The typed event to Deactivate/Activate the passthrough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassthroughFlip(pub bool);
+
 /// Use PassthroughFbExt::new(true) instead of Default if you want to have it at start up.
 ///
 ///
@@ -37,15 +43,9 @@ pub const PASSTHROUGH_FLIP: &str = "PassthroughFlip";
 /// let passthrough = true;
 /// let passthrough_enabled = stereokit_rust::system::BackendOpenXR::ext_enabled("XR_FB_passthrough");
 /// if passthrough_enabled {
-///    sk.push_action(StepperAction::add_default::<PassthroughFbExt>(
-///        "PassthroughFbExt",
-///    ));
+///    sk.add_stepper_default::<PassthroughFbExt>("PassthroughFbExt");
 ///    if passthrough {
-///        sk.push_action(StepperAction::event(
-///            "main".into(),
-///            PASSTHROUGH_FLIP,
-///            "1",
-///        ));
+///        sk.push_action(StepperAction::typed_event("main".into(), PassthroughFlip(true)));
 ///        Log::diag("Passthrough Activated at start !!");
 ///    } else {
 ///        Log::diag("Passthrough Deactived at start !!");
@@ -57,15 +57,12 @@ pub const PASSTHROUGH_FLIP: &str = "PassthroughFlip";
 ///  // Activate/Deactivate the stepper as follow :
 ///  if passthrough_enabled && passthrough != new_passthrough_value {
 ///      passthrough = new_passthrough_value;
-///          let mut string_value = "0";
-///          if passthrough {
-///              Log::diag("Activate passthrough");
-///              string_value = "1";
-///          } else {
-///              Log::diag("Deactivate passthrough");
-///          }
-///          sk.push_action(StepperAction::event("main".into(), PASSTHROUGH_FLIP, string_value))
+///      if passthrough {
+///          Log::diag("Activate passthrough");
+///      } else {
+///          Log::diag("Deactivate passthrough");
 ///      }
+///      sk.push_action(StepperAction::typed_event("main".into(), PassthroughFlip(passthrough)))
 ///  }
 /// ```
 
@@ -75,6 +72,7 @@ pub struct PassthroughFbExt {
     ext_available: bool,
     enabled: bool,
     enable_on_init: bool,
+    resume_enabled: bool,
     active_passtrough: PassthroughFB,
     active_layer: PassthroughLayerFB,
     old_color: Color128,
@@ -90,8 +88,6 @@ pub struct PassthroughFbExt {
     xr_passthrough_layer_set_style_fb: Option<PassthroughLayerSetStyleFB>,
 }
 
-unsafe impl Send for PassthroughFbExt {}
-
 impl Default for PassthroughFbExt {
     fn default() -> Self {
         Self {
@@ -100,6 +96,7 @@ impl Default for PassthroughFbExt {
             ext_available: false,
             enabled: false,
             enable_on_init: false,
+            resume_enabled: false,
             active_passtrough: PassthroughFB::from_raw(0),
             active_layer: PassthroughLayerFB::from_raw(0),
             old_color: Color128::WHITE,
@@ -129,16 +126,23 @@ impl Default for PassthroughFbExt {
 
 /// All the code here run in the main thread
 impl IStepper for PassthroughFbExt {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> std::result::Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
 
-        self.ext_available = Backend::xr_type() == BackendXRType::OpenXR
-            && BackendOpenXR::ext_enabled("XR_FB_passthrough")
-            && self.load_binding()
-            && self.init_passthrough();
-
-        self.ext_available
+        let reason = if Backend::xr_type() != BackendXRType::OpenXR {
+            "OpenXR backend is required"
+        } else if !BackendOpenXR::ext_enabled("XR_FB_passthrough") {
+            "XR_FB_passthrough is not enabled"
+        } else if !self.load_binding() {
+            "some XR_FB_passthrough functions are missing"
+        } else if !self.init_passthrough() {
+            "passthrough creation failed"
+        } else {
+            self.ext_available = true;
+            return Ok(());
+        };
+        Err(StereoKitError::StepperInit(self.id.clone(), reason.to_string()))
     }
 
     fn enabled(&self) -> bool {
@@ -147,16 +151,11 @@ impl IStepper for PassthroughFbExt {
 
     fn step(&mut self, token: &MainThreadToken) {
         // Here with enable/disable the passthrough
-        for e in token.get_event_report().iter() {
-            if let StepperAction::Event(_, key, value) = e {
-                if key.eq(PASSTHROUGH_FLIP) {
-                    if value == "0" {
-                        self.enable(false)
-                    } else {
-                        self.enable(true)
-                    }
-                }
-            }
+        for (_, PassthroughFlip(value)) in token.get_events::<PassthroughFlip>() {
+            self.enable(*value)
+        }
+        for (_, event) in token.get_events::<StringEvent>().filter(|(_, e)| e.key.eq(PASSTHROUGH_FLIP)) {
+            self.enable(event.value != "0")
         }
         if self.enabled() {
             let mut layer = CompositionLayerPassthroughFB {
@@ -170,6 +169,18 @@ impl IStepper for PassthroughFbExt {
         }
     }
 
+    fn on_suspend(&mut self) {
+        self.resume_enabled = self.enabled;
+        self.enable(false);
+    }
+
+    fn on_resume(&mut self) {
+        if self.resume_enabled {
+            self.resume_enabled = false;
+            self.enable(true);
+        }
+    }
+
     fn shutdown(&mut self) {
         if self.enabled {
             self.enable(false);
@@ -0,0 +1,140 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    event_loop::{IStepper, Profiler, StepperId, StepperOrder, StepperParams, StepperPhase, StringEvent},
+    maths::{units::CM, Pose, Quat, Vec2, Vec3},
+    sk::{MainThreadToken, SkInfo},
+    ui::Ui,
+    StereoKitError,
+};
+
+/// The string event key to show/hide the profiler window, prefer the typed event [`ShowProfilerWindow`].
+pub const SHOW_PROFILER_WINDOW: &str = "ShowProfilerWindow";
+
+/// The typed event to show/hide the profiler window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowProfilerWindow(pub bool);
+
+/// A window listing the steppers sorted by their cost in CPU time. The [`Profiler`] is enabled while the window is
+/// shown, and disabled when it is hidden only if the window was the one enabling it.
+pub struct ProfilerWindow {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    enabled: bool,
+    pub pose: Pose,
+    /// The maximum number of steppers listed.
+    pub max_lines: usize,
+    profiler: Profiler,
+    /// true if the profiler was enabled by this window.
+    profiler_enabled_here: bool,
+}
+
+impl Default for ProfilerWindow {
+    fn default() -> Self {
+        Self {
+            id: "ProfilerWindow".to_string(),
+            sk_info: None,
+            enabled: false,
+            pose: Pose::new(Vec3::new(0.7, 1.0, -0.3), Some(Quat::look_dir(Vec3::new(-1.0, 0.0, 1.0)))),
+            max_lines: 20,
+            profiler: Profiler::default(),
+            profiler_enabled_here: false,
+        }
+    }
+}
+
+impl IStepper for ProfilerWindow {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
+        self.id = id;
+        self.profiler = sk_info.borrow().get_profiler();
+        self.sk_info = Some(sk_info);
+        self.profiler_enabled_here = false;
+        self.enable_profiler(self.enabled);
+
+        Ok(())
+    }
+
+    fn order(&self) -> StepperOrder {
+        StepperOrder::new(StepperPhase::Ui)
+    }
+
+    fn step(&mut self, token: &MainThreadToken) {
+        for (_, ShowProfilerWindow(show)) in token.get_events::<ShowProfilerWindow>() {
+            self.show(*show)
+        }
+        for (_, event) in token.get_events::<StringEvent>().filter(|(_, e)| e.key.eq(SHOW_PROFILER_WINDOW)) {
+            self.show(event.get_bool().unwrap_or(false))
+        }
+        self.draw()
+    }
+
+    fn shutdown(&mut self) {
+        self.enable_profiler(false);
+    }
+
+    fn save_state(&self) -> Option<StepperParams> {
+        let mut state = StepperParams::new();
+        state.set_pose("pose", self.pose).set("max_lines", self.max_lines);
+        Some(state)
+    }
+
+    fn load_state(&mut self, state: &StepperParams) {
+        if let Some(pose) = state.get_pose("pose") {
+            self.pose = pose;
+        }
+        self.max_lines = state.get_or("max_lines", self.max_lines);
+    }
+}
+
+impl ProfilerWindow {
+    pub fn show(&mut self, value: bool) {
+        self.enabled = value;
+        self.enable_profiler(value);
+    }
+
+    /// Enable the profiler if it is not already, disable it only if this window enabled it.
+    fn enable_profiler(&mut self, value: bool) {
+        if value && !self.profiler.is_enabled() {
+            self.profiler.enable(true);
+            self.profiler_enabled_here = true;
+        } else if !value && self.profiler_enabled_here {
+            self.profiler.enable(false);
+            self.profiler_enabled_here = false;
+        }
+    }
+
+    fn draw(&mut self) {
+        if !self.enabled {
+            return;
+        };
+
+        Ui::window_begin("Profiler", &mut self.pose, Some(Vec2::new(40.0, 0.0) * CM), None, None);
+        let timings = self.profiler.get_timings();
+        let total: f64 = timings.iter().map(|timing| timing.average.as_secs_f64()).sum();
+        Ui::label(format!("{} steppers, {:.3} ms per frame", timings.len(), total * 1000.0), None, true);
+        Ui::same_line();
+        if Ui::button("Reset", None) {
+            self.profiler.reset();
+        }
+        Ui::hseparator();
+        Ui::label(format!("{:<32}{:>10}{:>10}{:>10}", "id", "avg ms", "max ms", "last ms"), None, false);
+        for timing in timings.iter().take(self.max_lines) {
+            Ui::label(
+                format!(
+                    "{:<32}{:>10.3}{:>10.3}{:>10.3}",
+                    timing.id,
+                    timing.average.as_secs_f64() * 1000.0,
+                    timing.max.as_secs_f64() * 1000.0,
+                    timing.last.as_secs_f64() * 1000.0
+                ),
+                None,
+                false,
+            );
+        }
+        Ui::window_end();
+    }
+}
@@ -1,13 +1,14 @@
 use std::{cell::RefCell, rc::Rc, sync::Mutex};
 
 use crate::{
-    event_loop::{IStepper, StepperAction, StepperId},
+    event_loop::{IStepper, StepperId, StepperOrder, StepperParams, StepperPhase, StringEvent},
     maths::{units::CM, Pose, Quat, Vec2, Vec3},
     sk::{MainThreadToken, SkInfo},
     system::{Assets, Log, Renderer},
     tex::{Tex, TexFormat, TexType},
     ui::Ui,
     util::{Color128, PickerMode, Platform},
+    StereoKitError,
 };
 
 use crate::sprite::Sprite;
@@ -15,8 +16,13 @@ use crate::sprite::Sprite;
 /// Somewhere to store the selected filename
 static FILE_NAME: Mutex<String> = Mutex::new(String::new());
 
+/// The string event key to show/hide the screenshot window, prefer the typed event [`ShowScreenshotWindow`].
 pub const SHOW_SCREENSHOT_WINDOW: &str = "ShowScreenshotWindow";
 
+/// The typed event to show/hide the screenshot window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowScreenshotWindow(pub bool);
+
 pub struct ScreenshotViewer {
     id: StepperId,
     sk_info: Option<Rc<RefCell<SkInfo>>>,
@@ -26,8 +32,6 @@ pub struct ScreenshotViewer {
     screen: Option<Sprite>,
 }
 
-unsafe impl Send for ScreenshotViewer {}
-
 impl Default for ScreenshotViewer {
     fn default() -> Self {
         let mut tex = Tex::gen_color(Color128::WHITE, 800, 600, TexType::Image, TexFormat::RGBA32);
@@ -49,23 +53,38 @@ impl IStepper for ScreenshotViewer {
         self.enabled
     }
 
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
 
-        true
+        Ok(())
+    }
+
+    fn order(&self) -> StepperOrder {
+        StepperOrder::new(StepperPhase::Ui)
     }
 
     fn step(&mut self, token: &MainThreadToken) {
-        for e in token.get_event_report().iter() {
-            if let StepperAction::Event(_, key, value) = e {
-                if key.eq(SHOW_SCREENSHOT_WINDOW) {
-                    self.enabled = value.parse().unwrap_or(false)
-                }
-            }
+        for (_, ShowScreenshotWindow(show)) in token.get_events::<ShowScreenshotWindow>() {
+            self.enabled = *show
+        }
+        for (_, event) in token.get_events::<StringEvent>().filter(|(_, e)| e.key.eq(SHOW_SCREENSHOT_WINDOW)) {
+            self.enabled = event.get_bool().unwrap_or(false)
         }
         self.draw(token)
     }
+
+    fn save_state(&self) -> Option<StepperParams> {
+        let mut state = StepperParams::new();
+        state.set_pose("pose", self.pose);
+        Some(state)
+    }
+
+    fn load_state(&mut self, state: &StepperParams) {
+        if let Some(pose) = state.get_pose("pose") {
+            self.pose = pose;
+        }
+    }
 }
 
 impl ScreenshotViewer {
@@ -12,9 +12,10 @@ use openxr_sys::{
 };
 
 use crate::{
-    event_loop::{IStepper, StepperAction, StepperId},
+    event_loop::{IStepper, StepperId, StringEvent},
     sk::{MainThreadToken, SkInfo},
     system::{Backend, BackendOpenXR, BackendXRType, Log},
+    StereoKitError,
 };
 use std::{cell::RefCell, ffi::c_void, ptr::null_mut, rc::Rc};
 
@@ -25,8 +26,13 @@ use std::{cell::RefCell, ffi::c_void, ptr::null_mut, rc::Rc};
 ///
 
 /// The StepperAction to trigger with the value "0"/"1" to Show/Hide the keyboard.
+/// Prefer the typed event [`KeyboardShow`].
 pub const KEYBOARD_SHOW: &str = "KeyboardShow";
 
+/// The typed event to Show/Hide the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyboardShow(pub bool);
+
 /// Use PassthroughFbExt::new(true) instead of Default if you want to have it at start up.
 ///
 ///
@@ -39,7 +45,7 @@ pub const KEYBOARD_SHOW: &str = "KeyboardShow";
 /// let mut passthrough = false;
 /// let passthrough_enabled = BackendOpenXR::ext_enabled("XR_FB_passthrough");
 /// if passthrough_enabled {
-///      sk.push_action(StepperAction::add_default::<PassthroughFbExt>("PassthroughFbExt"));
+///      sk.add_stepper_default::<PassthroughFbExt>("PassthroughFbExt");
 ///      Log::diag("Passthrough Disabled !!")
 ///  } else {
 ///      Log::diag("No Passthrough !!")
@@ -81,8 +87,6 @@ pub struct VirtualKbdMETA {
     xr_send_virtual_kbd_input: Option<SendVirtualKeyboardInputMETA>,
 }
 
-unsafe impl Send for VirtualKbdMETA {}
-
 impl Default for VirtualKbdMETA {
     fn default() -> Self {
         Self {
@@ -131,16 +135,23 @@ impl Default for VirtualKbdMETA {
 /// All the code here run in the main thread
 
 impl IStepper for VirtualKbdMETA {
-    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> bool {
+    fn initialize(&mut self, id: StepperId, sk_info: Rc<RefCell<SkInfo>>) -> std::result::Result<(), StereoKitError> {
         self.id = id;
         self.sk_info = Some(sk_info);
 
-        self.ext_available = Backend::xr_type() == BackendXRType::OpenXR
-            && BackendOpenXR::ext_enabled("XR_META_virtual_keyboard")
-            && self.load_binding()
-            && self.init_kbd();
-
-        self.ext_available
+        let reason = if Backend::xr_type() != BackendXRType::OpenXR {
+            "OpenXR backend is required"
+        } else if !BackendOpenXR::ext_enabled("XR_META_virtual_keyboard") {
+            "XR_META_virtual_keyboard is not enabled"
+        } else if !self.load_binding() {
+            "some XR_META_virtual_keyboard functions are missing"
+        } else if !self.init_kbd() {
+            "virtual keyboard creation failed"
+        } else {
+            self.ext_available = true;
+            return Ok(());
+        };
+        Err(StereoKitError::StepperInit(self.id.clone(), reason.to_string()))
     }
 
     fn enabled(&self) -> bool {
@@ -149,16 +160,11 @@ impl IStepper for VirtualKbdMETA {
 
     fn step(&mut self, token: &MainThreadToken) {
         // Here with enable/disable the passthrough
-        for e in token.get_event_report().iter() {
-            if let StepperAction::Event(_, key, value) = e {
-                if key.eq(KEYBOARD_SHOW) {
-                    if value == "0" {
-                        self.enable(false)
-                    } else {
-                        self.enable(true)
-                    }
-                }
-            }
+        for (_, KeyboardShow(value)) in token.get_events::<KeyboardShow>() {
+            self.enable(*value)
+        }
+        for (_, event) in token.get_events::<StringEvent>().filter(|(_, e)| e.key.eq(KEYBOARD_SHOW)) {
+            self.enable(event.value != "0")
         }
         if self.enabled() {
 
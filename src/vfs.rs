@@ -0,0 +1,560 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashSet},
+    fmt,
+    fs::{self, File},
+    io::{Cursor, Read, Seek},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use zip::ZipArchive;
+
+use crate::StereoKitError;
+
+/// An entry of a virtual directory, see [`Vfs::read_dir`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VfsEntry {
+    Dir(String),
+    File(String),
+}
+
+impl VfsEntry {
+    pub fn get_name(&self) -> &str {
+        match self {
+            VfsEntry::Dir(name) => name,
+            VfsEntry::File(name) => name,
+        }
+    }
+}
+
+/// A file embedded in the binary by [`crate::include_assets`]. Use it as the path of the file in the assets folder
+/// (`Tex::from_file(icons::RADIO_PNG, true, None)`) or give its data to the from_memory functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbeddedAsset {
+    /// The path relative to the assets folder, with '/' separators.
+    pub path: &'static str,
+    pub data: &'static [u8],
+}
+
+impl AsRef<Path> for EmbeddedAsset {
+    fn as_ref(&self) -> &Path {
+        Path::new(self.path)
+    }
+}
+
+enum MountSource {
+    Dir(PathBuf),
+    Zip(ZipArchive<File>),
+    ZipBytes(ZipArchive<Cursor<&'static [u8]>>),
+    Bundle(BTreeMap<String, &'static [u8]>),
+    #[cfg(target_os = "android")]
+    Android(ndk::asset::AssetManager),
+}
+
+struct Mount {
+    /// The virtual directory of the mount, "" for the root.
+    prefix: String,
+    priority: i32,
+    source: MountSource,
+    /// The sub directory of the extract directory for the files of this mount.
+    serial: u32,
+    /// The files of this mount already extracted by Vfs::get_file.
+    extracted: HashSet<PathBuf>,
+}
+
+impl Mount {
+    /// The path inside the mount of a virtual path, None if the path is not under the mount prefix.
+    fn inner_path<'a>(&self, path: &'a str) -> Option<&'a str> {
+        if self.prefix.is_empty() {
+            Some(path)
+        } else if path == self.prefix {
+            Some("")
+        } else {
+            path.strip_prefix(&self.prefix)?.strip_prefix('/')
+        }
+    }
+
+    fn read(&mut self, inner: &str) -> Option<Vec<u8>> {
+        match &mut self.source {
+            MountSource::Dir(dir) => fs::read(dir.join(inner)).ok(),
+            MountSource::Zip(archive) => read_zip(archive, inner),
+            MountSource::ZipBytes(archive) => read_zip(archive, inner),
+            MountSource::Bundle(files) => files.get(inner).map(|data| data.to_vec()),
+            #[cfg(target_os = "android")]
+            MountSource::Android(asset_manager) => {
+                let c_path = std::ffi::CString::new(inner).ok()?;
+                let mut asset = asset_manager.open(&c_path)?;
+                let mut data = vec![];
+                asset.read_to_end(&mut data).ok()?;
+                Some(data)
+            }
+        }
+    }
+
+    fn contains(&self, inner: &str) -> bool {
+        match &self.source {
+            MountSource::Dir(dir) => dir.join(inner).is_file(),
+            MountSource::Zip(archive) => archive.index_for_name(inner).is_some(),
+            MountSource::ZipBytes(archive) => archive.index_for_name(inner).is_some(),
+            MountSource::Bundle(files) => files.contains_key(inner),
+            #[cfg(target_os = "android")]
+            MountSource::Android(asset_manager) => {
+                std::ffi::CString::new(inner).ok().and_then(|c_path| asset_manager.open(&c_path)).is_some()
+            }
+        }
+    }
+
+    fn read_dir(&self, inner: &str, entries: &mut Vec<VfsEntry>) {
+        match &self.source {
+            MountSource::Dir(dir) => {
+                let Ok(read_dir) = fs::read_dir(dir.join(inner)) else { return };
+                for entry in read_dir.flatten() {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    match entry.file_type() {
+                        Ok(file_type) if file_type.is_dir() => entries.push(VfsEntry::Dir(name)),
+                        Ok(_) => entries.push(VfsEntry::File(name)),
+                        Err(_) => {}
+                    }
+                }
+            }
+            MountSource::Zip(archive) => list_names(archive.file_names(), inner, entries),
+            MountSource::ZipBytes(archive) => list_names(archive.file_names(), inner, entries),
+            MountSource::Bundle(files) => list_names(files.keys().map(|name| name.as_str()), inner, entries),
+            #[cfg(target_os = "android")]
+            MountSource::Android(asset_manager) => match list_android_assets(inner) {
+                // the Java asset manager lists the files and the directories, the directories are the names that
+                // can't be opened as a file
+                Some(names) => {
+                    for name in names {
+                        let child = if inner.is_empty() { name.clone() } else { format!("{}/{}", inner, name) };
+                        let is_file =
+                            std::ffi::CString::new(child).ok().and_then(|c_path| asset_manager.open(&c_path)).is_some();
+                        entries.push(if is_file { VfsEntry::File(name) } else { VfsEntry::Dir(name) });
+                    }
+                }
+                // without Java, the asset manager of the NDK only lists the files of a directory
+                None => {
+                    let Ok(c_path) = std::ffi::CString::new(inner) else { return };
+                    let Some(asset_dir) = asset_manager.open_dir(&c_path) else { return };
+                    for name in asset_dir {
+                        entries.push(VfsEntry::File(name.to_string_lossy().to_string()));
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// The files and directories of an assets directory of the APK, from the Java AssetManager. None if it can't be called.
+#[cfg(target_os = "android")]
+fn list_android_assets(dir: &str) -> Option<Vec<String>> {
+    use jni::objects::{JObject, JObjectArray, JString, JValue};
+
+    let ctx = ndk_context::android_context();
+    let vm = unsafe { jni::JavaVM::from_raw(ctx.vm() as _) }.ok()?;
+    let context = unsafe { JObject::from_raw(ctx.context() as _) };
+    let mut env = vm.attach_current_thread().ok()?;
+    let list = |env: &mut jni::JNIEnv| -> jni::errors::Result<Vec<String>> {
+        let asset_manager = env.call_method(&context, "getAssets", "()Landroid/content/res/AssetManager;", &[])?.l()?;
+        let j_dir = env.new_string(dir)?;
+        let names = env
+            .call_method(&asset_manager, "list", "(Ljava/lang/String;)[Ljava/lang/String;", &[JValue::Object(&j_dir)])?
+            .l()?;
+        let names = JObjectArray::from(names);
+        let count = env.get_array_length(&names)?;
+        let mut list = Vec::with_capacity(count as usize);
+        for i in 0..count {
+            let name = JString::from(env.get_object_array_element(&names, i)?);
+            list.push(env.get_string(&name)?.into());
+        }
+        Ok(list)
+    };
+    match list(&mut env) {
+        Ok(list) => Some(list),
+        Err(_) => {
+            let _ = env.exception_clear();
+            None
+        }
+    }
+}
+
+fn read_zip<R: Read + Seek>(archive: &mut ZipArchive<R>, inner: &str) -> Option<Vec<u8>> {
+    let mut file = archive.by_name(inner).ok()?;
+    let mut data = Vec::with_capacity(file.size() as usize);
+    file.read_to_end(&mut data).ok()?;
+    Some(data)
+}
+
+/// Add the children of directory `dir` from a list of file paths.
+fn list_names<'a>(names: impl Iterator<Item = &'a str>, dir: &str, entries: &mut Vec<VfsEntry>) {
+    for name in names {
+        let rest = if dir.is_empty() {
+            name
+        } else {
+            match name.strip_prefix(dir).and_then(|rest| rest.strip_prefix('/')) {
+                Some(rest) => rest,
+                None => continue,
+            }
+        };
+        match rest.split_once('/') {
+            Some((sub_dir, _)) if !sub_dir.is_empty() => entries.push(VfsEntry::Dir(sub_dir.to_string())),
+            Some(_) => {}
+            None if !rest.is_empty() => entries.push(VfsEntry::File(rest.to_string())),
+            None => {}
+        }
+    }
+}
+
+/// A virtual path: '/' separators, no empty, '.' or '..' components and no leading '/'.
+fn normalize(path: impl AsRef<Path>) -> String {
+    let path = path.as_ref().to_string_lossy().replace('\\', "/");
+    let mut components: Vec<&str> = vec![];
+    for component in path.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
+        }
+    }
+    components.join("/")
+}
+
+struct VfsData {
+    /// Sorted by priority, the highest first.
+    mounts: Vec<Mount>,
+    next_serial: u32,
+    extract_dir: PathBuf,
+}
+
+impl Default for VfsData {
+    fn default() -> Self {
+        // one directory per app, so two apps don't overwrite the files of each other
+        let app = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.file_stem().map(|stem| stem.to_string_lossy().to_string()))
+            .unwrap_or_else(|| format!("pid{}", std::process::id()));
+        Self { mounts: vec![], next_serial: 0, extract_dir: std::env::temp_dir().join("stereokit_vfs").join(app) }
+    }
+}
+
+/// A virtual file system where directories, zip archives and bundles of files embedded in the binary are mounted in
+/// priority order. A path is read from the mount with the highest priority having it, and the directories list the
+/// content of all the mounts, the same way on all platforms.
+///
+/// SkInfo::get_vfs gives the Vfs of the app, with the assets mounted at the root with priority 0 (the
+/// SkSettings::assets_folder on desktop, the assets of the APK on Android).
+/// ```ignore
+/// let vfs = sk_info.borrow().get_vfs();
+/// vfs.mount_zip("dlc", "downloads/dlc1.zip", 10)?;
+/// vfs.mount_bundle("ui", &[("close.png", include_bytes!("../assets/icons/close.png"))], 5);
+/// let floor = Tex::from_vfs(&vfs, "textures/parquet2/parquet2.ktx2", true, None)?;
+/// let plane = Model::from_vfs(&vfs, "dlc/plane.glb", None)?;
+/// for entry in vfs.read_dir("textures") {
+///     Log::diag(format!("{:?}", entry));
+/// }
+/// ```
+#[derive(Default, Clone)]
+pub struct Vfs {
+    data: Rc<RefCell<VfsData>>,
+}
+
+impl fmt::Debug for Vfs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = self.data.borrow();
+        let prefixes: Vec<(&str, i32)> =
+            data.mounts.iter().map(|mount| (mount.prefix.as_str(), mount.priority)).collect();
+        write!(f, "Vfs mounts: {:?}", prefixes)
+    }
+}
+
+impl Vfs {
+    /// The virtual path of this path, as used for the ids of the assets loaded from a Vfs: '/' separators, no empty,
+    /// '.' or '..' components and no leading '/'.
+    pub fn virtual_path(path: impl AsRef<Path>) -> String {
+        normalize(path)
+    }
+
+    /// A Vfs with the directory `assets_folder` mounted at the root (the current directory if empty).
+    pub fn with_assets_folder(assets_folder: impl AsRef<Path>) -> Self {
+        let assets_folder = assets_folder.as_ref();
+        let dir = if assets_folder.as_os_str().is_empty() { Path::new(".") } else { assets_folder };
+        let vfs = Self::default();
+        vfs.mount(String::new(), 0, MountSource::Dir(dir.to_path_buf()));
+        vfs
+    }
+
+    /// A Vfs with the assets of the APK mounted at the root. The extracted files go to `extract_dir`.
+    #[cfg(target_os = "android")]
+    pub fn with_android_assets(asset_manager: ndk::asset::AssetManager, extract_dir: Option<PathBuf>) -> Self {
+        let vfs = Self::default();
+        vfs.mount(String::new(), 0, MountSource::Android(asset_manager));
+        if let Some(extract_dir) = extract_dir {
+            vfs.extract_dir(extract_dir);
+        }
+        vfs
+    }
+
+    fn mount(&self, prefix: String, priority: i32, source: MountSource) {
+        let mut data = self.data.borrow_mut();
+        let serial = data.next_serial;
+        data.next_serial += 1;
+        // the last mounted wins between equal priorities
+        let index = data.mounts.iter().position(|mount| mount.priority <= priority).unwrap_or(data.mounts.len());
+        data.mounts.insert(index, Mount { prefix, priority, source, serial, extracted: HashSet::new() });
+    }
+
+    /// Mount a directory of the file system at the virtual directory `prefix` ("" for the root).
+    pub fn mount_dir(
+        &self,
+        prefix: impl AsRef<Path>,
+        dir: impl AsRef<Path>,
+        priority: i32,
+    ) -> Result<(), StereoKitError> {
+        let dir = dir.as_ref();
+        if !dir.is_dir() {
+            return Err(StereoKitError::DirectoryError(dir.to_string_lossy().to_string()));
+        }
+        self.mount(normalize(prefix), priority, MountSource::Dir(dir.to_path_buf()));
+        Ok(())
+    }
+
+    /// Mount a zip archive of the file system at the virtual directory `prefix` ("" for the root).
+    pub fn mount_zip(
+        &self,
+        prefix: impl AsRef<Path>,
+        zip: impl AsRef<Path>,
+        priority: i32,
+    ) -> Result<(), StereoKitError> {
+        let zip = zip.as_ref();
+        let invalid = |reason: String| StereoKitError::Vfs(zip.to_string_lossy().to_string(), reason);
+        let file = File::open(zip).map_err(|e| invalid(e.to_string()))?;
+        let archive = ZipArchive::new(file).map_err(|e| invalid(e.to_string()))?;
+        self.mount(normalize(prefix), priority, MountSource::Zip(archive));
+        Ok(())
+    }
+
+    /// Mount a zip archive embedded in the binary (with include_bytes!) at the virtual directory `prefix`.
+    pub fn mount_zip_bytes(
+        &self,
+        prefix: impl AsRef<Path>,
+        zip: &'static [u8],
+        priority: i32,
+    ) -> Result<(), StereoKitError> {
+        let prefix = normalize(prefix);
+        let archive = ZipArchive::new(Cursor::new(zip))
+            .map_err(|e| StereoKitError::Vfs(format!("{}/*.zip", prefix), e.to_string()))?;
+        self.mount(prefix, priority, MountSource::ZipBytes(archive));
+        Ok(())
+    }
+
+    /// Mount files embedded in the binary at the virtual directory `prefix`. The files are given with their path
+    /// relative to `prefix`.
+    pub fn mount_bundle(&self, prefix: impl AsRef<Path>, files: &[(&str, &'static [u8])], priority: i32) {
+        let files = files.iter().map(|(path, data)| (normalize(path), *data)).collect();
+        self.mount(normalize(prefix), priority, MountSource::Bundle(files));
+    }
+
+    /// Remove the mounts of the virtual directory `prefix`. Returns the number of mounts removed.
+    pub fn unmount(&self, prefix: impl AsRef<Path>) -> usize {
+        let prefix = normalize(prefix);
+        let mut data = self.data.borrow_mut();
+        let before = data.mounts.len();
+        data.mounts.retain(|mount| mount.prefix != prefix);
+        before - data.mounts.len()
+    }
+
+    /// The directory where the files needed as real files are extracted, in a sub directory per mount, see
+    /// [`Vfs::get_file`]. Default is `stereokit_vfs/<executable name>` in the temporary directory.
+    pub fn extract_dir(&self, dir: impl AsRef<Path>) {
+        self.data.borrow_mut().extract_dir = dir.as_ref().to_path_buf();
+    }
+
+    /// Does a mount have this file? Directories are not files, see [`Vfs::read_dir`].
+    pub fn exists(&self, path: impl AsRef<Path>) -> bool {
+        let path = normalize(path);
+        let data = self.data.borrow();
+        data.mounts.iter().any(|mount| mount.inner_path(&path).is_some_and(|inner| mount.contains(inner)))
+    }
+
+    /// Read a file from the mount with the highest priority having it.
+    pub fn read(&self, path: impl AsRef<Path>) -> Result<Vec<u8>, StereoKitError> {
+        let path = normalize(path);
+        let mut data = self.data.borrow_mut();
+        for mount in data.mounts.iter_mut() {
+            let Some(inner) = mount.inner_path(&path) else { continue };
+            let inner = inner.to_string();
+            if let Some(content) = mount.read(&inner) {
+                return Ok(content);
+            }
+        }
+        Err(StereoKitError::Vfs(path, "not found in the mounts".into()))
+    }
+
+    /// The path of the file system of this file if the mount with the highest priority having it is a directory.
+    pub fn get_dir_file(&self, path: impl AsRef<Path>) -> Option<PathBuf> {
+        let path = normalize(path);
+        let data = self.data.borrow();
+        for mount in data.mounts.iter() {
+            let Some(inner) = mount.inner_path(&path) else { continue };
+            if let MountSource::Dir(dir) = &mount.source {
+                if dir.join(inner).is_file() {
+                    return Some(dir.join(inner));
+                }
+            } else if mount.contains(inner) {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// A path of the file system for this file, for the loaders that only accept files. The files of the mounted
+    /// directories are given as they are (see [`Vfs::get_dir_file`]), the other ones are extracted first in a sub
+    /// directory of the mount (see [`Vfs::extract_dir`]). A file is extracted once per mount.
+    pub fn get_file(&self, path: impl AsRef<Path>) -> Result<PathBuf, StereoKitError> {
+        if let Some(file) = self.get_dir_file(&path) {
+            return Ok(file);
+        }
+        let path = normalize(path);
+        let mut data = self.data.borrow_mut();
+        let VfsData { mounts, extract_dir, .. } = &mut *data;
+        for mount in mounts.iter_mut() {
+            let Some(inner) = mount.inner_path(&path) else { continue };
+            let file = extract_dir.join(format!("mount{}", mount.serial)).join(inner);
+            if mount.extracted.contains(&file) && file.is_file() {
+                return Ok(file);
+            }
+            let Some(content) = mount.read(inner) else { continue };
+            let extracted =
+                file.parent().map(fs::create_dir_all).unwrap_or(Ok(())).and_then(|_| fs::write(&file, content));
+            extracted.map_err(|e| StereoKitError::Vfs(path.clone(), e.to_string()))?;
+            mount.extracted.insert(file.clone());
+            return Ok(file);
+        }
+        Err(StereoKitError::Vfs(path, "not found in the mounts".into()))
+    }
+
+    /// The files and sub directories of a virtual directory, from all the mounts, sorted by name with the directories
+    /// first. On Android the directories of the APK assets are listed by the Java AssetManager, if it can't be called
+    /// only the files are listed.
+    pub fn read_dir(&self, path: impl AsRef<Path>) -> Vec<VfsEntry> {
+        let path = normalize(path);
+        let data = self.data.borrow();
+        let mut entries = vec![];
+        for mount in data.mounts.iter() {
+            match mount.inner_path(&path) {
+                Some(inner) => mount.read_dir(inner, &mut entries),
+                // a mount below this directory appears as a sub directory
+                None => list_names(std::iter::once(format!("{}/", mount.prefix).as_str()), &path, &mut entries),
+            }
+        }
+        entries.sort();
+        entries.dedup();
+        entries
+    }
+
+    /// All the files under a virtual directory, with their virtual path.
+    pub fn walk(&self, path: impl AsRef<Path>) -> Vec<String> {
+        let path = normalize(path);
+        let mut files = vec![];
+        for entry in self.read_dir(&path) {
+            let entry_path = if path.is_empty() {
+                entry.get_name().to_string()
+            } else {
+                format!("{}/{}", path, entry.get_name())
+            };
+            match entry {
+                VfsEntry::Dir(_) => files.append(&mut self.walk(&entry_path)),
+                VfsEntry::File(_) => files.push(entry_path),
+            }
+        }
+        files
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_paths() {
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("/textures//floor.png"), "textures/floor.png");
+        assert_eq!(normalize("./textures/./floor.png"), "textures/floor.png");
+        assert_eq!(normalize("textures/../sounds/click.wav"), "sounds/click.wav");
+        assert_eq!(normalize("../../up.png"), "up.png");
+        assert_eq!(normalize("textures\\sky\\dawn.hdr"), "textures/sky/dawn.hdr");
+        assert_eq!(normalize("dlc/"), "dlc");
+        assert_eq!(Vfs::virtual_path("./a.png"), Vfs::virtual_path("a.png"));
+    }
+
+    #[test]
+    fn list_names_of_a_dir() {
+        let names = ["a.png", "textures/b.png", "textures/sky/c.hdr", "textures/sky/d.hdr", "texturesX/e.png"];
+        let mut root = vec![];
+        list_names(names.iter().copied(), "", &mut root);
+        root.sort();
+        root.dedup();
+        assert_eq!(
+            root,
+            vec![VfsEntry::Dir("textures".into()), VfsEntry::Dir("texturesX".into()), VfsEntry::File("a.png".into())]
+        );
+
+        let mut textures = vec![];
+        list_names(names.iter().copied(), "textures", &mut textures);
+        textures.sort();
+        textures.dedup();
+        assert_eq!(textures, vec![VfsEntry::Dir("sky".into()), VfsEntry::File("b.png".into())]);
+
+        let mut empty = vec![];
+        list_names(names.iter().copied(), "sounds", &mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn mount_priority_order() {
+        let vfs = Vfs::default();
+        vfs.mount_bundle("", &[("a.txt", b"low"), ("low.txt", b"low")], 0);
+        vfs.mount_bundle("", &[("a.txt", b"high")], 10);
+        vfs.mount_bundle("", &[("a.txt", b"last high")], 10);
+        vfs.mount_bundle("dlc", &[("a.txt", b"dlc")], 20);
+
+        assert_eq!(vfs.read("a.txt").unwrap(), b"last high");
+        assert_eq!(vfs.read("low.txt").unwrap(), b"low");
+        assert_eq!(vfs.read("dlc/a.txt").unwrap(), b"dlc");
+        assert!(vfs.read("dlc/low.txt").is_err());
+        assert!(vfs.exists("./dlc/../low.txt"));
+        assert_eq!(
+            vfs.read_dir(""),
+            vec![VfsEntry::Dir("dlc".into()), VfsEntry::File("a.txt".into()), VfsEntry::File("low.txt".into())]
+        );
+        assert_eq!(vfs.walk(""), vec!["dlc/a.txt".to_string(), "a.txt".into(), "low.txt".into()]);
+
+        assert_eq!(vfs.unmount(""), 3);
+        assert_eq!(vfs.read("a.txt").ok(), None);
+        assert_eq!(vfs.read("dlc/a.txt").unwrap(), b"dlc");
+    }
+
+    #[test]
+    fn extract_once() {
+        let dir = std::env::temp_dir().join(format!("stereokit_vfs_test{}", std::process::id()));
+        let vfs = Vfs::default();
+        vfs.extract_dir(&dir);
+        vfs.mount_bundle("ui", &[("icons/close.png", b"png")], 0);
+
+        let file = vfs.get_file("ui/./icons/close.png").unwrap();
+        assert!(file.starts_with(&dir));
+        assert_eq!(fs::read(&file).unwrap(), b"png");
+        // an extracted file is not written again
+        fs::write(&file, b"kept").unwrap();
+        assert_eq!(vfs.get_file("ui/icons/close.png").unwrap(), file);
+        assert_eq!(fs::read(&file).unwrap(), b"kept");
+        // unless it has been removed
+        fs::remove_file(&file).unwrap();
+        assert_eq!(fs::read(vfs.get_file("ui/icons/close.png").unwrap()).unwrap(), b"png");
+        assert!(vfs.get_file("ui/icons/open.png").is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
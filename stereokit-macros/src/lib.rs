@@ -5,7 +5,7 @@ use std::{
 };
 
 //use proc_macro::{Delimiter, Group, Literal, Punct, Spacing, TokenStream, TokenTree};
-use proc_macro::{TokenStream, TokenTree};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 /// Embed the tree of the assets sub-directories in your crate.
 /// useful if you want to browse some assets
@@ -59,3 +59,301 @@ fn get_sub_dirs(path_assets: PathBuf, sub_path: &Path) -> Vec<String> {
     }
     vec_path
 }
+
+/// Check at compile time that a file exists in the `assets` directory of your crate and give its path as a &str.
+/// The file is a dependency of the build (without being embedded in the binary), so deleting or renaming it fails the
+/// next build.
+/// ```ignore
+/// let radio = Tex::from_file(asset_path!("icons/radio.png"), true, None)?;
+/// // error: asset "icons/radios.png" not found in .../assets
+/// ```
+#[proc_macro]
+pub fn asset_path(body: TokenStream) -> TokenStream {
+    let args = split_args(body);
+    let Some((path, span)) = args.first().and_then(|arg| get_str(arg)) else {
+        return compile_error("asset_path! needs the path of a file of the assets directory", Span::call_site());
+    };
+    let file = assets_dir().join(&path);
+    if !file.is_file() {
+        return compile_error(&format!("asset {:?} not found in {:?}", path, assets_dir()), span);
+    }
+    // include_bytes! makes cargo track the file, the unused constant is not kept in the binary
+    let tracked = format!("const _: &[u8] = include_bytes!({:?});", file.to_string_lossy());
+    let mut literal = Literal::string(&path);
+    literal.set_span(span);
+    let mut body = TokenStream::from_str(&tracked).unwrap();
+    body.extend([TokenTree::Literal(literal)]);
+    TokenStream::from(TokenTree::Group(Group::new(Delimiter::Brace, body)))
+}
+
+/// Embed the files of an `assets` sub directory (recursively) in your crate as a `&[(&str, &[u8])]` ready for
+/// Vfs::mount_bundle. The paths are relative to the `assets` directory, so mount them at the root. The files can be
+/// filtered by extensions ("" for the whole `assets` directory).
+/// ```ignore
+/// vfs.mount_bundle("", include_asset_bundle!("icons", ".png", ".jpg"), 10);
+/// ```
+#[proc_macro]
+pub fn include_asset_bundle(body: TokenStream) -> TokenStream {
+    let args = split_args(body);
+    let (sub_dir, exts, span) = match get_dir_and_exts(&args, 0) {
+        Ok(dir_and_exts) => dir_and_exts,
+        Err(error) => return error,
+    };
+    let mut files = vec![];
+    get_files(&assets_dir().join(&sub_dir), Path::new(&sub_dir), &exts, &mut files);
+    if files.is_empty() {
+        return compile_error(&format!("no asset found in {:?} with extensions {:?}", sub_dir, exts), span);
+    }
+    let entries: Vec<String> = files
+        .iter()
+        .map(|(path, file)| format!("({:?}, include_bytes!({:?}) as &[u8])", path, file))
+        .collect();
+    TokenStream::from_str(&format!("{{ const FILES: &[(&str, &[u8])] = &[{}]; FILES }}", entries.join(", "))).unwrap()
+}
+
+/// Embed the files of an `assets` sub directory (recursively) in your crate and generate a module with a
+/// stereokit_rust::vfs::EmbeddedAsset constant per file, and a sub module per sub directory. The constants are named
+/// after the file names in SCREAMING_SNAKE_CASE and `BUNDLE` gives all the files of the module for
+/// Vfs::mount_bundle. The files can be filtered by extensions.
+/// ```ignore
+/// include_assets!(pub icons, "icons", ".png");
+///
+/// let radio = Tex::from_file(icons::RADIO_PNG, true, None)?;
+/// let checked = Tex::from_memory(icons::CHECKED_PNG.data, true, None)?;
+/// vfs.mount_bundle("", icons::BUNDLE, 10);
+/// ```
+/// A file added to the directory is only seen at the next build of the module.
+#[proc_macro]
+pub fn include_assets(body: TokenStream) -> TokenStream {
+    let args = split_args(body);
+    let Some(TokenTree::Ident(name)) = args.first().and_then(|arg| arg.last()) else {
+        return compile_error("include_assets! needs a module name, then an assets sub directory", Span::call_site());
+    };
+    let visibility: Vec<String> = args[0][..args[0].len() - 1].iter().map(|token| token.to_string()).collect();
+    let (sub_dir, exts, span) = match get_dir_and_exts(&args, 1) {
+        Ok(dir_and_exts) => dir_and_exts,
+        Err(error) => return error,
+    };
+    let dir = assets_dir().join(&sub_dir);
+    if !dir.is_dir() {
+        return compile_error(&format!("asset directory {:?} not found in {:?}", sub_dir, assets_dir()), span);
+    }
+    match get_module(&name.to_string(), &dir, Path::new(&sub_dir), &exts) {
+        Ok((module, _)) => TokenStream::from_str(&format!("{} {}", visibility.join(" "), module)).unwrap(),
+        Err(error) => compile_error(&error, span),
+    }
+}
+
+/// The source of a module with the constants of the files of `dir`, and the paths of the constants of its BUNDLE.
+fn get_module(name: &str, dir: &Path, sub_path: &Path, exts: &[String]) -> Result<(String, Vec<String>), String> {
+    let mut entries: Vec<PathBuf> = read_dir(dir).map_err(|e| e.to_string())?.flatten().map(|e| e.path()).collect();
+    entries.sort();
+    let mut items = vec![];
+    let mut names: Vec<String> = vec![];
+    let mut bundle = vec![];
+    for entry in entries {
+        let Some(file_name) = entry.file_name().map(|file_name| file_name.to_string_lossy().to_string()) else {
+            continue;
+        };
+        let path = sub_path.join(&file_name);
+        if entry.is_dir() {
+            let sub_name = to_ident(&file_name, false);
+            let (module, sub_bundle) = get_module(&sub_name, &entry, &path, exts)?;
+            if sub_bundle.is_empty() {
+                continue;
+            }
+            check_unique(&mut names, sub_name.clone(), &path)?;
+            items.push(format!("pub {}", module));
+            bundle.extend(sub_bundle.into_iter().map(|constant| format!("{}::{}", sub_name, constant)));
+        } else if has_extension(&entry, exts) {
+            let const_name = to_ident(&file_name, true);
+            check_unique(&mut names, const_name.clone(), &path)?;
+            items.push(format!(
+                "pub const {}: stereokit_rust::vfs::EmbeddedAsset = stereokit_rust::vfs::EmbeddedAsset {{ path: {:?}, data: include_bytes!({:?}) }};",
+                const_name,
+                to_asset_path(&path),
+                entry.to_string_lossy()
+            ));
+            bundle.push(const_name);
+        }
+    }
+    let bundle_items: Vec<String> = bundle.iter().map(|constant| format!("({0}.path, {0}.data)", constant)).collect();
+    items.push(format!("pub const BUNDLE: &[(&str, &[u8])] = &[{}];", bundle_items.join(", ")));
+    Ok((format!("mod {} {{ {} }}", name, items.join(" ")), bundle))
+}
+
+fn check_unique(names: &mut Vec<String>, name: String, path: &Path) -> Result<(), String> {
+    if names.contains(&name) || name == "BUNDLE" {
+        return Err(format!("asset {:?} gives the name {} already used in its module", path, name));
+    }
+    names.push(name);
+    Ok(())
+}
+
+/// The files of `dir` (recursively) having one of the extensions, with their path relative to the assets directory.
+fn get_files(dir: &Path, sub_path: &Path, exts: &[String], files: &mut Vec<(String, String)>) {
+    let Ok(read_dir) = read_dir(dir) else { return };
+    let mut entries: Vec<PathBuf> = read_dir.flatten().map(|entry| entry.path()).collect();
+    entries.sort();
+    for entry in entries {
+        let Some(file_name) = entry.file_name() else { continue };
+        let path = sub_path.join(file_name);
+        if entry.is_dir() {
+            get_files(&entry, &path, exts, files);
+        } else if has_extension(&entry, exts) {
+            files.push((to_asset_path(&path), entry.to_string_lossy().to_string()));
+        }
+    }
+}
+
+/// The sub directory (a string literal at `index`) and the extensions following it.
+fn get_dir_and_exts(args: &[Vec<TokenTree>], index: usize) -> Result<(String, Vec<String>, Span), TokenStream> {
+    let Some((sub_dir, span)) = args.get(index).and_then(|arg| get_str(arg)) else {
+        return Err(compile_error("the assets sub directory is missing", Span::call_site()));
+    };
+    let mut exts = vec![];
+    for arg in &args[index + 1..] {
+        match get_str(arg) {
+            Some((ext, _)) => exts.push(ext.trim_start_matches('.').to_lowercase()),
+            None => return Err(compile_error("the extensions must be string literals like \".png\"", span)),
+        }
+    }
+    Ok((sub_dir, exts, span))
+}
+
+fn has_extension(path: &Path, exts: &[String]) -> bool {
+    exts.is_empty() || path.extension().is_some_and(|ext| exts.contains(&ext.to_string_lossy().to_lowercase()))
+}
+
+/// The `assets` directory of the crate using the macro.
+fn assets_dir() -> PathBuf {
+    let cargo_dir = std::env::var("CARGO_MANIFEST_DIR").ok().unwrap();
+    Path::new(&cargo_dir).join("assets")
+}
+
+/// An asset path with '/' separators whatever the platform.
+fn to_asset_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// A valid identifier from a file name, SCREAMING_SNAKE_CASE for a constant or snake_case for a module.
+fn to_ident(file_name: &str, constant: bool) -> String {
+    let mut ident = String::new();
+    for c in file_name.chars() {
+        if c.is_ascii_alphanumeric() {
+            ident.push(if constant { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() });
+        } else if !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    let mut ident = ident.trim_matches('_').to_string();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+        "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+        "static", "struct", "super", "trait", "true", "try", "type", "unsafe", "use", "where", "while", "yield",
+    ];
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    ident
+}
+
+/// The arguments of a macro, separated by commas.
+fn split_args(body: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut args = vec![vec![]];
+    for token in body {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(vec![]),
+            token => args.last_mut().unwrap().push(token),
+        }
+    }
+    args.retain(|arg| !arg.is_empty());
+    args
+}
+
+/// The value of a string literal argument.
+fn get_str(arg: &[TokenTree]) -> Option<(String, Span)> {
+    let token = match arg {
+        [TokenTree::Literal(literal)] => literal.clone(),
+        // the literals given by macro_rules! are wrapped in an invisible group
+        [TokenTree::Group(group)] if group.delimiter() == Delimiter::None => {
+            return get_str(&group.stream().into_iter().collect::<Vec<_>>());
+        }
+        _ => return None,
+    };
+    Some((parse_str(&token.to_string())?, token.span()))
+}
+
+/// The value of a string literal, raw or not, with its escapes resolved. None if this is not a string literal.
+fn parse_str(text: &str) -> Option<String> {
+    if let Some(raw) = text.strip_prefix('r') {
+        let hashes = "#".repeat(raw.len() - raw.trim_start_matches('#').len());
+        let value = raw.strip_prefix(&hashes)?.strip_suffix(&hashes)?;
+        return Some(value.strip_prefix('"')?.strip_suffix('"')?.to_string());
+    }
+    let mut chars = text.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => value.push('\n'),
+            'r' => value.push('\r'),
+            't' => value.push('\t'),
+            '0' => value.push('\0'),
+            c @ ('\\' | '"' | '\'') => value.push(c),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                value.push(char::from(u8::from_str_radix(&hex, 16).ok().filter(|byte| byte.is_ascii())?));
+            }
+            'u' => {
+                let rest = chars.as_str().strip_prefix('{')?;
+                let (hex, tail) = rest.split_once('}')?;
+                value.push(char::from_u32(u32::from_str_radix(&hex.replace('_', ""), 16).ok()?)?);
+                chars = tail.chars();
+            }
+            // a line continuation skips the line break and the leading whitespaces of the next line
+            '\n' => chars = chars.as_str().trim_start().chars(),
+            _ => return None,
+        }
+    }
+    Some(value)
+}
+
+/// A compile_error!(message) located at span.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut group = Group::new(Delimiter::Parenthesis, TokenStream::from(TokenTree::Literal(message)));
+    group.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    [TokenTree::Ident(Ident::new("compile_error", span)), TokenTree::Punct(bang), TokenTree::Group(group)]
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_str;
+
+    #[test]
+    fn parse_string_literals() {
+        assert_eq!(parse_str(r#""icons/radio.png""#).as_deref(), Some("icons/radio.png"));
+        assert_eq!(parse_str(r#""dir\\file \"1\".png""#).as_deref(), Some("dir\\file \"1\".png"));
+        assert_eq!(parse_str(r#""caf\u{e9}\x21\t""#).as_deref(), Some("café!\t"));
+        assert_eq!(parse_str("\"icons/\\\n    radio.png\"").as_deref(), Some("icons/radio.png"));
+        assert_eq!(parse_str(r#"r"C:\assets\radio.png""#).as_deref(), Some(r"C:\assets\radio.png"));
+        assert_eq!(parse_str(r###"r#"say "hi".png"#"###).as_deref(), Some(r#"say "hi".png"#));
+        assert_eq!(parse_str(r#"b"radio.png""#), None);
+        assert_eq!(parse_str("42"), None);
+    }
+}